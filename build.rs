@@ -1,8 +1,80 @@
+use std::{
+    env,
+    path::PathBuf,
+};
+
 fn main() {
-    println!("cargo:rustc-flags=-L /usr/local/lib/");
-    println!("cargo:rustc-link-search=/usr/local/lib/");
-    println!("cargo:rustc-link-lib=Ultralight");
-    println!("cargo:rustc-link-lib=WebCore");
+    println!("cargo:rerun-if-env-changed=ULTRALIGHT_SDK_DIR");
+
+    let sdk_dir = env::var("ULTRALIGHT_SDK_DIR").ok().map(PathBuf::from);
+
+    let include_paths = link_ultralight(sdk_dir.as_ref());
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&include_paths);
+
+    #[cfg(not(feature = "bindgen"))]
+    let _ = include_paths;
+}
+
+// Returns the directories holding the SDK headers, as far as they are known
+fn link_ultralight(sdk_dir: Option<&PathBuf>) -> Vec<PathBuf> {
+    // An explicitly configured SDK takes precedence; the Linux SDK ships
+    // its shared objects in `bin/`, older drops used `lib/`.
+    if let Some(sdk_dir) = sdk_dir {
+        for sub_dir in &["bin", "lib"] {
+            let lib_dir = sdk_dir.join(sub_dir);
+
+            if lib_dir.is_dir() {
+                println!("cargo:rustc-link-search=native={}", lib_dir.display());
+            }
+        }
+
+        println!("cargo:rustc-link-lib=dylib=Ultralight");
+        println!("cargo:rustc-link-lib=dylib=WebCore");
+
+        return vec![sdk_dir.join("include")];
+    }
+
+    // pkg-config emits the link-search and link-lib lines itself
+    if let Ok(library) = pkg_config::Config::new().probe("Ultralight") {
+        return library.include_paths;
+    }
+
+    // Leave it to the system linker search path (ld.so.conf, LIBRARY_PATH)
+    println!("cargo:rustc-link-lib=dylib=Ultralight");
+    println!("cargo:rustc-link-lib=dylib=WebCore");
+
+    Vec::new()
 }
 
-// bindgen --use-core --impl-debug --impl-partialeq --generate-inline-functions --dump-preprocessed-input --conservative-inline-namespaces --whitelist-function "^UL.*|JS.*|ul.*|WK.*" --whitelist-var "^UL.*|JS.*|ul.*|WK.*" --whitelist-type "^UL.*|JS.*|ul.*|WK.*"
+// Regenerates src/ffi.rs into $OUT_DIR from <Ultralight/CAPI.h>. Without the
+// `bindgen` feature the checked-in src/ffi.rs is used as-is.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_paths: &[PathBuf]) {
+    const ALLOWLIST: &str = "^UL.*|JS.*|ul.*|WK.*";
+
+    let mut builder = bindgen::Builder::default()
+        .header_contents("wrapper.h", "#include <Ultralight/CAPI.h>\n")
+        .use_core()
+        .impl_debug(true)
+        .impl_partialeq(true)
+        .generate_inline_functions(true)
+        .conservative_inline_namespaces()
+        .allowlist_function(ALLOWLIST)
+        .allowlist_var(ALLOWLIST)
+        .allowlist_type(ALLOWLIST)
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()));
+
+    for include_path in include_paths {
+        builder = builder.clang_arg(format!("-I{}", include_path.display()));
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("ffi.rs");
+
+    builder
+        .generate()
+        .expect("unable to generate bindings from <Ultralight/CAPI.h>")
+        .write_to_file(&out_path)
+        .expect("unable to write generated bindings");
+}
@@ -0,0 +1,136 @@
+use crate::{
+    helpers_internal::quote_string,
+    Error,
+    MouseButton,
+    Result,
+    Ultralight,
+};
+
+// Scrolls the element into view and returns the centre of its box, `[]` if
+// it has no box or something else covers its centre, or `null` if no
+// element matches.
+const LOCATE_ELEMENT: &str = r#"(function (selector) {
+    var element = document.querySelector(selector);
+
+    if (element === null) {
+        return null;
+    }
+
+    element.scrollIntoView({ block: "center", inline: "center" });
+
+    var rect = element.getBoundingClientRect();
+    var style = window.getComputedStyle(element);
+
+    if (rect.width === 0 || rect.height === 0 || style.visibility === "hidden") {
+        return [];
+    }
+
+    var x = rect.left + rect.width / 2;
+    var y = rect.top + rect.height / 2;
+    var hit = document.elementFromPoint(x, y);
+
+    if (hit === null || !(hit === element || element.contains(hit))) {
+        return [];
+    }
+
+    return [x, y];
+})"#;
+
+const CLEAR_VALUE: &str = r#"(function (selector) {
+    var element = document.querySelector(selector);
+
+    if (element !== null && "value" in element) {
+        element.value = "";
+        element.dispatchEvent(new Event("input", { bubbles: true }));
+    }
+})"#;
+
+// Returns whether a matching option was found.
+const SELECT_OPTION: &str = r#"(function (selector, value) {
+    var element = document.querySelector(selector);
+
+    if (element === null || !element.options) {
+        return false;
+    }
+
+    for (var i = 0; i < element.options.length; i++) {
+        var option = element.options[i];
+
+        if (option.value === value || option.text === value) {
+            element.selectedIndex = i;
+            element.dispatchEvent(new Event("input", { bubbles: true }));
+            element.dispatchEvent(new Event("change", { bubbles: true }));
+
+            return true;
+        }
+    }
+
+    return false;
+})"#;
+
+impl Ultralight {
+    /// Scroll the first element matching `selector` into view and return
+    /// the view coordinates of its centre.
+    ///
+    /// Fails with `Error::ElementNotFound` or `Error::ElementNotVisible`.
+    pub fn locate(&mut self, selector: impl AsRef<str>) -> Result<(i32, i32)> {
+        let selector = selector.as_ref();
+
+        let script = format!("{}({})", LOCATE_ELEMENT, quote_string(selector));
+
+        match self.evaluate::<Option<Vec<f64>>>(script)? {
+            None => Err(Error::ElementNotFound(selector.to_string())),
+            Some(point) if point.len() == 2 => Ok((point[0] as i32, point[1] as i32)),
+            Some(_) => Err(Error::ElementNotVisible(selector.to_string())),
+        }
+    }
+
+    /// Left-click the centre of the element matching `selector`.
+    pub fn click_selector(&mut self, selector: impl AsRef<str>) -> Result<()> {
+        let (x, y) = self.locate(selector)?;
+
+        self.click(x, y, MouseButton::Left)
+    }
+
+    /// Move the mouse over the element matching `selector`.
+    pub fn hover(&mut self, selector: impl AsRef<str>) -> Result<()> {
+        let (x, y) = self.locate(selector)?;
+
+        self.mouse_move(x, y)
+    }
+
+    /// Click the element matching `selector` to focus it, clear its value
+    /// and type `text` into it.
+    pub fn fill(&mut self, selector: impl AsRef<str>, text: impl AsRef<str>) -> Result<()> {
+        let selector = selector.as_ref();
+
+        self.click_selector(selector)?;
+
+        self.evaluate_script(format!("{}({})", CLEAR_VALUE, quote_string(selector)))?;
+
+        self.type_text(text)
+    }
+
+    /// Select the option whose value or label is `value` in the `<select>`
+    /// matching `selector`, firing `input` and `change` like a user would.
+    ///
+    /// Fails with `Error::OptionNotFound` if there is no such option or the
+    /// element is not a `<select>`.
+    pub fn select_option(&mut self, selector: impl AsRef<str>, value: impl AsRef<str>) -> Result<()> {
+        let selector = selector.as_ref();
+        let value = value.as_ref();
+
+        self.locate(selector)?;
+
+        let script = format!("{}({}, {})", SELECT_OPTION, quote_string(selector), quote_string(value));
+
+        if self.evaluate::<bool>(script)? {
+            Ok(())
+        } else {
+            Err(Error::OptionNotFound {
+                selector: selector.to_string(),
+                value: value.to_string(),
+            })
+        }
+    }
+}
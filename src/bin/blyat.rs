@@ -0,0 +1,274 @@
+use blyat::{
+    Condition,
+    Config,
+    ErrorPolicy,
+    Ultralight,
+};
+
+use std::{
+    env,
+    fs,
+    path::Path,
+    process,
+    time::Duration,
+};
+
+static USAGE: &str = "\
+usage: blyat shot <url|file> [options]
+
+options:
+    -o, --output <file>     where to write the PNG (default: output.png)
+        --width <px>        viewport width (default: 1920)
+        --height <px>       viewport height (default: 1080)
+        --full-page         capture the whole document, not just the viewport
+        --transparent       keep the page background transparent
+        --breakpoints <w,..>
+                            capture once per width, writing <output>-<w>.png
+        --timeout <ms>      give up if the page has not loaded after <ms>
+                            (default: 30000)
+        --wait-for <ms>     keep the page running for <ms> after it has loaded
+        --wait-for-selector <css>
+                            after loading, wait until an element matches <css>
+        --fail-on-console-error
+                            exit with an error if the page logs JS or network errors
+        --allow-console-error <pattern>
+                            ignore console errors matching <pattern> (`*` is a
+                            wildcard); implies --fail-on-console-error
+";
+
+struct ShotOptions {
+    target: String,
+    output: String,
+    width: u32,
+    height: u32,
+    full_page: bool,
+    transparent: bool,
+    breakpoints: Vec<u32>,
+    timeout: Duration,
+    wait_for: Option<Duration>,
+    wait_for_selector: Option<String>,
+    error_policy: Option<ErrorPolicy>,
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} expects a value", flag))?;
+
+    value.parse().map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
+
+fn parse_size(flag: &str, value: Option<String>) -> Result<u32, String> {
+    match parse_number(flag, value)? {
+        0 => Err(format!("invalid value for {}: sizes must be positive", flag)),
+        size => Ok(size),
+    }
+}
+
+fn parse_shot_args<I>(mut args: I) -> Result<ShotOptions, String>
+    where I: Iterator<Item = String>
+{
+    let mut target = None;
+    let mut output = "output.png".to_string();
+    let mut width = 1920;
+    let mut height = 1080;
+    let mut full_page = false;
+    let mut transparent = false;
+    let mut breakpoints = Vec::new();
+    let mut timeout = Duration::from_secs(30);
+    let mut wait_for = None;
+    let mut wait_for_selector = None;
+    let mut error_policy = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => {
+                output = args.next().ok_or_else(|| format!("{} expects a value", arg))?;
+            },
+            "--width" => width = parse_size(&arg, args.next())?,
+            "--height" => height = parse_size(&arg, args.next())?,
+            "--full-page" => full_page = true,
+            "--transparent" => transparent = true,
+            "--breakpoints" => {
+                let value = args.next().ok_or_else(|| format!("{} expects a value", arg))?;
+
+                breakpoints = value.split(',')
+                    .map(|width| parse_size(&arg, Some(width.trim().to_string())))
+                    .collect::<Result<Vec<u32>, _>>()?;
+            },
+            "--timeout" => timeout = Duration::from_millis(parse_number(&arg, args.next())?),
+            "--wait-for" => {
+                wait_for = Some(Duration::from_millis(parse_number(&arg, args.next())?));
+            },
+            "--wait-for-selector" => {
+                wait_for_selector = Some(args.next().ok_or_else(|| format!("{} expects a value", arg))?);
+            },
+            "--fail-on-console-error" => {
+                error_policy.get_or_insert_with(ErrorPolicy::new);
+            },
+            "--allow-console-error" => {
+                let pattern = args.next().ok_or_else(|| format!("{} expects a value", arg))?;
+
+                error_policy = Some(error_policy.unwrap_or_default().allow(pattern));
+            },
+            flag if flag.starts_with('-') => return Err(format!("unknown option: {}", flag)),
+            _ if target.is_none() => target = Some(arg),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    Ok(ShotOptions {
+        target: target.ok_or("missing <url|file>")?,
+        output,
+        width,
+        height,
+        full_page,
+        transparent,
+        breakpoints,
+        timeout,
+        wait_for,
+        wait_for_selector,
+        error_policy,
+    })
+}
+
+fn shot(options: ShotOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::new();
+
+    let mut ul = Ultralight::new(Some(config), None);
+
+    ul.view(options.width, options.height, options.transparent);
+    ul.log_to_stdout()?;
+    ul.set_console_error_policy(options.error_policy);
+
+    if Path::new(&options.target).is_file() {
+        ul.load_html(fs::read_to_string(&options.target)?)?;
+    } else {
+        ul.load_url(&options.target)?;
+    }
+
+    ul.wait_until_loaded(options.timeout)?;
+
+    if let Some(selector) = options.wait_for_selector {
+        ul.wait_for(Condition::Selector(selector), options.timeout)?;
+    }
+
+    if let Some(wait_for) = options.wait_for {
+        ul.wait_for(Condition::Delay(wait_for), options.timeout)?;
+    }
+
+    // scripts may have thrown while we kept the page running
+    ul.check_console_errors()?;
+
+    if !options.breakpoints.is_empty() {
+        let captures = ul.capture_breakpoints(&options.breakpoints, options.full_page)?;
+
+        let output = Path::new(&options.output);
+        let stem = output.file_stem().and_then(|stem| stem.to_str()).unwrap_or("output");
+
+        for (width, capture) in options.breakpoints.iter().zip(captures) {
+            capture.write_png(output.with_file_name(format!("{}-{}.png", stem, width)))?;
+        }
+    } else if options.full_page {
+        ul.capture_full_page()?.write_png(&options.output)?;
+    } else {
+        ul.render();
+        ul.write_png_to_file(&options.output)?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("shot") => parse_shot_args(args)
+            .map_err(|err| format!("{}\n\n{}", err, USAGE))
+            .and_then(|options| shot(options).map_err(|err| err.to_string())),
+        Some("-h") | Some("--help") => {
+            print!("{}", USAGE);
+            return;
+        },
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err.trim_end());
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<ShotOptions, String> {
+        parse_shot_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn parse_err(args: &[&str]) -> String {
+        parse(args).err().expect("arguments should be rejected")
+    }
+
+    #[test]
+    fn defaults() {
+        let options = parse(&["page.html"]).unwrap_or_else(|err| panic!("{}", err));
+
+        assert_eq!(options.target, "page.html");
+        assert_eq!(options.output, "output.png");
+        assert_eq!((options.width, options.height), (1920, 1080));
+        assert!(!options.full_page);
+        assert!(!options.transparent);
+        assert!(options.breakpoints.is_empty());
+        assert_eq!(options.timeout, Duration::from_secs(30));
+        assert_eq!(options.wait_for, None);
+        assert_eq!(options.wait_for_selector, None);
+        assert_eq!(options.error_policy, None);
+    }
+
+    #[test]
+    fn missing_values() {
+        assert_eq!(parse_err(&["page.html", "--width"]), "--width expects a value");
+        assert_eq!(parse_err(&["page.html", "-o"]), "-o expects a value");
+        assert_eq!(parse_err(&[]), "missing <url|file>");
+    }
+
+    #[test]
+    fn unknown_flag() {
+        assert_eq!(parse_err(&["page.html", "--fast"]), "unknown option: --fast");
+    }
+
+    #[test]
+    fn second_positional_argument() {
+        assert_eq!(parse_err(&["page.html", "other.html"]), "unexpected argument: other.html");
+    }
+
+    #[test]
+    fn zero_sizes() {
+        assert!(parse_err(&["page.html", "--width", "0"]).contains("must be positive"));
+        assert!(parse_err(&["page.html", "--height", "0"]).contains("must be positive"));
+    }
+
+    #[test]
+    fn breakpoints() {
+        let options = parse(&["page.html", "--breakpoints", "320, 768,1280"]).unwrap_or_else(|err| panic!("{}", err));
+
+        assert_eq!(options.breakpoints, vec![320, 768, 1280]);
+
+        assert!(parse_err(&["page.html", "--breakpoints", "320,0"]).contains("must be positive"));
+        assert_eq!(
+            parse_err(&["page.html", "--breakpoints", "320,wide"]),
+            "invalid value for --breakpoints: wide"
+        );
+    }
+
+    #[test]
+    fn allowed_console_errors_imply_a_policy() {
+        let options = parse(&["page.html", "--allow-console-error", "favicon*"]).unwrap_or_else(|err| panic!("{}", err));
+
+        assert_eq!(options.error_policy, Some(ErrorPolicy::new().allow("favicon*")));
+
+        let options = parse(&["page.html", "--fail-on-console-error"]).unwrap_or_else(|err| panic!("{}", err));
+
+        assert_eq!(options.error_policy, Some(ErrorPolicy::new()));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    ffi,
+    error::{
+        Error,
+        Result,
+    },
+    helpers_internal::path_to_cstring,
+};
+
+use std::{
+    os::raw::c_void,
+    path::Path,
+};
+
+/// Owning handle to a `ULBitmap` created by us, destroyed on drop.
+///
+/// Bitmaps returned by `ulViewGetBitmap` belong to their view and are never
+/// wrapped in this type.
+pub struct Bitmap {
+    raw: ffi::ULBitmap,
+}
+
+impl Bitmap {
+    /// Create an RGBA8 bitmap holding a copy of `pixels`, with rows packed
+    /// tightly.
+    pub fn from_pixels(width: u32, height: u32, pixels: &[u8]) -> Bitmap {
+        Bitmap::from_pixels_with_row_bytes(width, height, width * 4, pixels)
+    }
+
+    /// Create an RGBA8 bitmap holding a copy of `pixels`, whose rows start
+    /// `row_bytes` apart.
+    pub fn from_pixels_with_row_bytes(width: u32, height: u32, row_bytes: u32, pixels: &[u8]) -> Bitmap {
+        Bitmap {
+            raw: unsafe {
+                ffi::ulCreateBitmapFromPixels(
+                    width,
+                    height,
+                    ffi::ULBitmapFormat_kBitmapFormat_RGBA8,
+                    row_bytes,
+                    pixels.as_ptr() as *const c_void,
+                    pixels.len(),
+                    true,
+                )
+            }
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        unsafe {
+            ffi::ulBitmapGetWidth(self.raw)
+        }
+    }
+
+    pub fn height(&self) -> u32 {
+        unsafe {
+            ffi::ulBitmapGetHeight(self.raw)
+        }
+    }
+
+    /// Write bitmap to a PNG on disk.
+    pub fn write_png(&self, file_name: impl AsRef<Path>) -> Result<()> {
+        let file_name = file_name.as_ref();
+        let fn_c_str = path_to_cstring(file_name)?;
+
+        let written = unsafe {
+            ffi::ulBitmapWritePNG(
+                self.raw,
+                fn_c_str.as_ptr()
+            )
+        };
+
+        if written {
+            Ok(())
+        } else {
+            Err(Error::BitmapWriteFailed(file_name.to_path_buf()))
+        }
+    }
+}
+
+impl Drop for Bitmap {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ulDestroyBitmap(self.raw);
+        }
+    }
+}
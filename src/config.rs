@@ -1,4 +1,7 @@
-use crate::ffi;
+use crate::{
+    ffi,
+    strings::UlString,
+};
 
 macro_rules! config_item (
     ($name:ident, $type:ty, $comment:expr) => (
@@ -11,9 +14,9 @@ macro_rules! config_item (
 
 macro_rules! set_config (
     ($config: expr, $self: expr, $name:ident, $ffiName:ident) => (
-        if $self.$name.is_some() {
+        if let Some(value) = $self.$name {
             unsafe {
-                ffi::$ffiName($config, $self.$name.unwrap());
+                ffi::$ffiName($config.raw, value);
             }
         }
     )
@@ -21,20 +24,35 @@ macro_rules! set_config (
 
 macro_rules! set_config_str (
     ($config: expr, $self: expr, $name:ident, $ffiName:ident) => (
-        if $self.$name.is_some() {
+        if let Some(ref value) = $self.$name {
             unsafe {
-                let str = ffi::ulCreateString(
-                    std::ffi::CString::new(
-                        $self.$name.clone().unwrap()
-                    ).unwrap().as_ptr()
-                );
+                let str = UlString::new(value);
 
-                ffi::$ffiName($config, str);
+                ffi::$ffiName($config.raw, str.raw());
             }
         }
     )
 );
 
+/// Owning handle to a `ULConfig`, destroyed on drop.
+pub struct NativeConfig {
+    raw: ffi::ULConfig,
+}
+
+impl NativeConfig {
+    pub(crate) fn raw(&self) -> ffi::ULConfig {
+        self.raw
+    }
+}
+
+impl Drop for NativeConfig {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ulDestroyConfig(self.raw);
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct UltralightConfig {
     enableImages: Option<bool>,
@@ -65,9 +83,11 @@ impl UltralightConfig {
         }
     }
 
-    pub fn to_ulconfig(&self) -> ffi::ULConfig {
-        let config = unsafe {
-            ffi::ulCreateConfig()
+    pub fn to_ulconfig(&self) -> NativeConfig {
+        let config = NativeConfig {
+            raw: unsafe {
+                ffi::ulCreateConfig()
+            }
         };
 
         set_config!(config, self, enableImages, ulConfigSetEnableImages);
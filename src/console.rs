@@ -0,0 +1,408 @@
+use crate::{
+    ffi,
+    helpers_internal::{
+        quote_string,
+        ViewConsoleCallback,
+    },
+    View,
+};
+
+use std::{
+    cell::RefCell,
+    fmt,
+    io::Write,
+    rc::Rc,
+};
+
+/// Severity of a console message, mapped from `ULMessageLevel`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageLevel {
+    Log,
+    Warning,
+    Error,
+    Debug,
+    Info,
+}
+
+impl MessageLevel {
+    pub(crate) fn from_raw(raw: ffi::ULMessageLevel) -> Option<MessageLevel> {
+        match raw {
+            ffi::ULMessageLevel_kMessageLevel_Log => Some(MessageLevel::Log),
+            ffi::ULMessageLevel_kMessageLevel_Warning => Some(MessageLevel::Warning),
+            ffi::ULMessageLevel_kMessageLevel_Error => Some(MessageLevel::Error),
+            ffi::ULMessageLevel_kMessageLevel_Debug => Some(MessageLevel::Debug),
+            ffi::ULMessageLevel_kMessageLevel_Info => Some(MessageLevel::Info),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageLevel::Log => "log",
+            MessageLevel::Warning => "warning",
+            MessageLevel::Error => "error",
+            MessageLevel::Debug => "debug",
+            MessageLevel::Info => "info",
+        }
+    }
+}
+
+/// Subsystem a console message originates from, mapped from `ULMessageSource`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageSource {
+    XML,
+    JS,
+    Network,
+    ConsoleAPI,
+    Storage,
+    AppCache,
+    Rendering,
+    CSS,
+    Security,
+    ContentBlocker,
+    Other,
+}
+
+impl MessageSource {
+    pub(crate) fn from_raw(raw: ffi::ULMessageSource) -> Option<MessageSource> {
+        match raw {
+            ffi::ULMessageSource_kMessageSource_XML => Some(MessageSource::XML),
+            ffi::ULMessageSource_kMessageSource_JS => Some(MessageSource::JS),
+            ffi::ULMessageSource_kMessageSource_Network => Some(MessageSource::Network),
+            ffi::ULMessageSource_kMessageSource_ConsoleAPI => Some(MessageSource::ConsoleAPI),
+            ffi::ULMessageSource_kMessageSource_Storage => Some(MessageSource::Storage),
+            ffi::ULMessageSource_kMessageSource_AppCache => Some(MessageSource::AppCache),
+            ffi::ULMessageSource_kMessageSource_Rendering => Some(MessageSource::Rendering),
+            ffi::ULMessageSource_kMessageSource_CSS => Some(MessageSource::CSS),
+            ffi::ULMessageSource_kMessageSource_Security => Some(MessageSource::Security),
+            ffi::ULMessageSource_kMessageSource_ContentBlocker => Some(MessageSource::ContentBlocker),
+            ffi::ULMessageSource_kMessageSource_Other => Some(MessageSource::Other),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageSource::XML => "xml",
+            MessageSource::JS => "js",
+            MessageSource::Network => "network",
+            MessageSource::ConsoleAPI => "consoleapi",
+            MessageSource::Storage => "storage",
+            MessageSource::AppCache => "appcache",
+            MessageSource::Rendering => "rendering",
+            MessageSource::CSS => "css",
+            MessageSource::Security => "security",
+            MessageSource::ContentBlocker => "contentblocker",
+            MessageSource::Other => "other",
+        }
+    }
+}
+
+/// A message the page added to the console, including engine diagnostics
+/// such as failed requests and uncaught exceptions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleMessage {
+    pub level: MessageLevel,
+    pub source: MessageSource,
+    pub message: String,
+    pub line: u32,
+    pub column: u32,
+    /// URL of the script or document the message refers to; may be empty.
+    pub source_id: String,
+}
+
+impl ConsoleMessage {
+    /// Serialize the message as a single-line JSON object.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"level\":\"{}\",\"source\":\"{}\",\"message\":{},\"line\":{},\"column\":{},\"source_id\":{}}}",
+            self.level.as_str(),
+            self.source.as_str(),
+            quote_string(&self.message),
+            self.line,
+            self.column,
+            quote_string(&self.source_id)
+        )
+    }
+}
+
+impl fmt::Display for ConsoleMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}] [{}] {} ({}:{}:{})",
+            self.level.as_str(),
+            self.source.as_str(),
+            self.message,
+            self.source_id,
+            self.line,
+            self.column
+        )
+    }
+}
+
+/// Sink that keeps every message it receives.
+///
+/// Clones share the same buffer, so one clone can be handed to
+/// `on_console_message` while another is used to read the messages back.
+#[derive(Debug, Clone, Default)]
+pub struct Collector {
+    messages: Rc<RefCell<Vec<ConsoleMessage>>>,
+}
+
+impl Collector {
+    pub fn new() -> Collector {
+        Collector::default()
+    }
+
+    /// Closure that appends to this collector.
+    pub fn sink(&self) -> impl FnMut(&ConsoleMessage) + 'static {
+        let messages = self.messages.clone();
+
+        move |message| messages.borrow_mut().push(message.clone())
+    }
+
+    /// Copy of the messages received so far.
+    pub fn messages(&self) -> Vec<ConsoleMessage> {
+        self.messages.borrow().clone()
+    }
+
+    /// Remove and return the messages received so far.
+    pub fn take(&self) -> Vec<ConsoleMessage> {
+        self.messages.borrow_mut().split_off(0)
+    }
+}
+
+/// Sink printing every message to stdout.
+pub fn stdout() -> impl FnMut(&ConsoleMessage) + 'static {
+    |message| println!("{}", message)
+}
+
+/// Sink writing one JSON object per message to `writer`, typically a `File`.
+///
+/// Write errors are ignored; the page keeps running either way.
+pub fn json_lines<W: Write + 'static>(mut writer: W) -> impl FnMut(&ConsoleMessage) + 'static {
+    move |message| {
+        let _ = writeln!(writer, "{}", message.to_json());
+        let _ = writer.flush();
+    }
+}
+
+/// Sink forwarding messages to the `log` crate under the `blyat::console`
+/// target. `console.log` is logged at `Info`.
+#[cfg(feature = "log")]
+pub fn log() -> impl FnMut(&ConsoleMessage) + 'static {
+    |message| {
+        let level = match message.level {
+            MessageLevel::Error => ::log::Level::Error,
+            MessageLevel::Warning => ::log::Level::Warn,
+            MessageLevel::Log | MessageLevel::Info => ::log::Level::Info,
+            MessageLevel::Debug => ::log::Level::Debug,
+        };
+
+        ::log::log!(
+            target: "blyat::console",
+            level,
+            "[{}] {} ({}:{}:{})",
+            message.source.as_str(),
+            message.message,
+            message.source_id,
+            message.line,
+            message.column
+        );
+    }
+}
+
+/// Decides which console messages make a load fail, see
+/// `Ultralight::set_console_error_policy`.
+///
+/// By default every `Error` level message from JavaScript or the network
+/// counts. Allow-list patterns are matched against the message text and the
+/// source id; `*` matches any run of characters and the pattern may match
+/// anywhere, so `"favicon.ico"` ignores every error mentioning the favicon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPolicy {
+    sources: Vec<MessageSource>,
+    allowed: Vec<String>,
+}
+
+impl Default for ErrorPolicy {
+    fn default() -> ErrorPolicy {
+        ErrorPolicy {
+            sources: vec![MessageSource::JS, MessageSource::Network],
+            allowed: Vec::new(),
+        }
+    }
+}
+
+impl ErrorPolicy {
+    pub fn new() -> ErrorPolicy {
+        ErrorPolicy::default()
+    }
+
+    /// Replace the sources whose errors are recorded.
+    pub fn sources(mut self, sources: &[MessageSource]) -> ErrorPolicy {
+        self.sources = sources.to_vec();
+        self
+    }
+
+    /// Ignore errors matching `pattern`.
+    pub fn allow(mut self, pattern: impl Into<String>) -> ErrorPolicy {
+        self.allowed.push(pattern.into());
+        self
+    }
+
+    /// Whether `message` violates this policy.
+    pub fn is_violation(&self, message: &ConsoleMessage) -> bool {
+        message.level == MessageLevel::Error
+            && self.sources.contains(&message.source)
+            && !self.allowed.iter().any(|pattern| {
+                pattern_matches(pattern, &message.message) || pattern_matches(pattern, &message.source_id)
+            })
+    }
+}
+
+fn pattern_matches(pattern: &str, text: &str) -> bool {
+    let mut rest = text;
+
+    for part in pattern.split('*') {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    true
+}
+
+pub(crate) type ConsoleSink = Box<dyn FnMut(&ConsoleMessage)>;
+
+/// Console handling shared by all views of an `Ultralight`: the user's
+/// sink and callback plus the errors recorded under the active `ErrorPolicy`.
+#[derive(Default)]
+pub(crate) struct ConsoleState {
+    pub(crate) sink: Option<ConsoleSink>,
+    pub(crate) callback: Option<ViewConsoleCallback>,
+    pub(crate) policy: Option<ErrorPolicy>,
+    pub(crate) errors: Vec<ConsoleMessage>,
+}
+
+impl ConsoleState {
+    /// Record `message` if it violates the policy, then pass it to the sink
+    /// and the callback.
+    ///
+    /// The sink and callback are taken out of the state while they run, so
+    /// the state stays usable if they log to the console themselves. Such
+    /// nested messages are dropped before they get here, because the view
+    /// does not call back into a callback that is still running.
+    pub(crate) fn dispatch(state: &RefCell<ConsoleState>, view: &View, message: &ConsoleMessage) {
+        let (mut sink, mut callback) = {
+            let mut state = state.borrow_mut();
+
+            if let Some(ref policy) = state.policy {
+                if policy.is_violation(message) {
+                    state.errors.push(message.clone());
+                }
+            }
+
+            (state.sink.take(), state.callback.take())
+        };
+
+        if let Some(ref mut sink) = sink {
+            sink(message);
+        }
+
+        if let Some(ref mut callback) = callback {
+            callback(view, message);
+        }
+
+        let mut state = state.borrow_mut();
+
+        // keep whatever was installed while they ran
+        if state.sink.is_none() {
+            state.sink = sink;
+        }
+
+        if state.callback.is_none() {
+            state.callback = callback;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(level: MessageLevel, source: MessageSource, text: &str) -> ConsoleMessage {
+        ConsoleMessage {
+            level,
+            source,
+            message: text.to_string(),
+            line: 1,
+            column: 1,
+            source_id: "https://example.com/app.js".to_string(),
+        }
+    }
+
+    #[test]
+    fn patterns_match_anywhere() {
+        assert!(pattern_matches("favicon", "GET /favicon.ico 404"));
+        assert!(pattern_matches("GET", "GET /favicon.ico 404"));
+        assert!(pattern_matches("404", "GET /favicon.ico 404"));
+        assert!(!pattern_matches("500", "GET /favicon.ico 404"));
+    }
+
+    #[test]
+    fn wildcards_match_in_order() {
+        assert!(pattern_matches("GET*404", "GET /favicon.ico 404"));
+        assert!(pattern_matches("*.ico*", "GET /favicon.ico 404"));
+        assert!(pattern_matches("a*a", "aa"));
+        assert!(!pattern_matches("404*GET", "GET /favicon.ico 404"));
+        assert!(!pattern_matches("a*a", "a"));
+    }
+
+    #[test]
+    fn empty_patterns_match_everything() {
+        assert!(pattern_matches("", ""));
+        assert!(pattern_matches("", "anything"));
+        assert!(pattern_matches("*", "anything"));
+    }
+
+    #[test]
+    fn policy_records_js_and_network_errors() {
+        let policy = ErrorPolicy::new();
+
+        assert!(policy.is_violation(&message(MessageLevel::Error, MessageSource::JS, "TypeError")));
+        assert!(policy.is_violation(&message(MessageLevel::Error, MessageSource::Network, "404")));
+        assert!(!policy.is_violation(&message(MessageLevel::Error, MessageSource::CSS, "bad rule")));
+        assert!(!policy.is_violation(&message(MessageLevel::Warning, MessageSource::JS, "deprecated")));
+    }
+
+    #[test]
+    fn policy_sources_replace_the_defaults() {
+        let policy = ErrorPolicy::new().sources(&[MessageSource::CSS]);
+
+        assert!(policy.is_violation(&message(MessageLevel::Error, MessageSource::CSS, "bad rule")));
+        assert!(!policy.is_violation(&message(MessageLevel::Error, MessageSource::JS, "TypeError")));
+    }
+
+    #[test]
+    fn allowed_patterns_check_message_and_source_id() {
+        let policy = ErrorPolicy::new().allow("favicon").allow("*/vendor/*");
+
+        assert!(!policy.is_violation(&message(MessageLevel::Error, MessageSource::Network, "GET /favicon.ico 404")));
+        assert!(policy.is_violation(&message(MessageLevel::Error, MessageSource::Network, "GET /logo.png 404")));
+
+        let mut vendored = message(MessageLevel::Error, MessageSource::JS, "TypeError");
+        vendored.source_id = "https://example.com/vendor/lib.js".to_string();
+
+        assert!(!policy.is_violation(&vendored));
+    }
+
+    #[test]
+    fn empty_allowed_pattern_allows_everything() {
+        let policy = ErrorPolicy::new().allow("");
+
+        assert!(!policy.is_violation(&message(MessageLevel::Error, MessageSource::JS, "TypeError")));
+    }
+}
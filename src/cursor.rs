@@ -0,0 +1,102 @@
+use crate::ffi;
+
+/// Mouse cursor requested by the page, mapped from `ULCursor`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Cursor {
+    Pointer,
+    Cross,
+    Hand,
+    IBeam,
+    Wait,
+    Help,
+    EastResize,
+    NorthResize,
+    NorthEastResize,
+    NorthWestResize,
+    SouthResize,
+    SouthEastResize,
+    SouthWestResize,
+    WestResize,
+    NorthSouthResize,
+    EastWestResize,
+    NorthEastSouthWestResize,
+    NorthWestSouthEastResize,
+    ColumnResize,
+    RowResize,
+    MiddlePanning,
+    EastPanning,
+    NorthPanning,
+    NorthEastPanning,
+    NorthWestPanning,
+    SouthPanning,
+    SouthEastPanning,
+    SouthWestPanning,
+    WestPanning,
+    Move,
+    VerticalText,
+    Cell,
+    ContextMenu,
+    Alias,
+    Progress,
+    NoDrop,
+    Copy,
+    None,
+    NotAllowed,
+    ZoomIn,
+    ZoomOut,
+    Grab,
+    Grabbing,
+    Custom,
+}
+
+impl Cursor {
+    pub(crate) fn from_raw(cursor: ffi::ULCursor) -> Option<Cursor> {
+        match cursor {
+            ffi::ULCursor_kCursor_Pointer => Some(Cursor::Pointer),
+            ffi::ULCursor_kCursor_Cross => Some(Cursor::Cross),
+            ffi::ULCursor_kCursor_Hand => Some(Cursor::Hand),
+            ffi::ULCursor_kCursor_IBeam => Some(Cursor::IBeam),
+            ffi::ULCursor_kCursor_Wait => Some(Cursor::Wait),
+            ffi::ULCursor_kCursor_Help => Some(Cursor::Help),
+            ffi::ULCursor_kCursor_EastResize => Some(Cursor::EastResize),
+            ffi::ULCursor_kCursor_NorthResize => Some(Cursor::NorthResize),
+            ffi::ULCursor_kCursor_NorthEastResize => Some(Cursor::NorthEastResize),
+            ffi::ULCursor_kCursor_NorthWestResize => Some(Cursor::NorthWestResize),
+            ffi::ULCursor_kCursor_SouthResize => Some(Cursor::SouthResize),
+            ffi::ULCursor_kCursor_SouthEastResize => Some(Cursor::SouthEastResize),
+            ffi::ULCursor_kCursor_SouthWestResize => Some(Cursor::SouthWestResize),
+            ffi::ULCursor_kCursor_WestResize => Some(Cursor::WestResize),
+            ffi::ULCursor_kCursor_NorthSouthResize => Some(Cursor::NorthSouthResize),
+            ffi::ULCursor_kCursor_EastWestResize => Some(Cursor::EastWestResize),
+            ffi::ULCursor_kCursor_NorthEastSouthWestResize => Some(Cursor::NorthEastSouthWestResize),
+            ffi::ULCursor_kCursor_NorthWestSouthEastResize => Some(Cursor::NorthWestSouthEastResize),
+            ffi::ULCursor_kCursor_ColumnResize => Some(Cursor::ColumnResize),
+            ffi::ULCursor_kCursor_RowResize => Some(Cursor::RowResize),
+            ffi::ULCursor_kCursor_MiddlePanning => Some(Cursor::MiddlePanning),
+            ffi::ULCursor_kCursor_EastPanning => Some(Cursor::EastPanning),
+            ffi::ULCursor_kCursor_NorthPanning => Some(Cursor::NorthPanning),
+            ffi::ULCursor_kCursor_NorthEastPanning => Some(Cursor::NorthEastPanning),
+            ffi::ULCursor_kCursor_NorthWestPanning => Some(Cursor::NorthWestPanning),
+            ffi::ULCursor_kCursor_SouthPanning => Some(Cursor::SouthPanning),
+            ffi::ULCursor_kCursor_SouthEastPanning => Some(Cursor::SouthEastPanning),
+            ffi::ULCursor_kCursor_SouthWestPanning => Some(Cursor::SouthWestPanning),
+            ffi::ULCursor_kCursor_WestPanning => Some(Cursor::WestPanning),
+            ffi::ULCursor_kCursor_Move => Some(Cursor::Move),
+            ffi::ULCursor_kCursor_VerticalText => Some(Cursor::VerticalText),
+            ffi::ULCursor_kCursor_Cell => Some(Cursor::Cell),
+            ffi::ULCursor_kCursor_ContextMenu => Some(Cursor::ContextMenu),
+            ffi::ULCursor_kCursor_Alias => Some(Cursor::Alias),
+            ffi::ULCursor_kCursor_Progress => Some(Cursor::Progress),
+            ffi::ULCursor_kCursor_NoDrop => Some(Cursor::NoDrop),
+            ffi::ULCursor_kCursor_Copy => Some(Cursor::Copy),
+            ffi::ULCursor_kCursor_None => Some(Cursor::None),
+            ffi::ULCursor_kCursor_NotAllowed => Some(Cursor::NotAllowed),
+            ffi::ULCursor_kCursor_ZoomIn => Some(Cursor::ZoomIn),
+            ffi::ULCursor_kCursor_ZoomOut => Some(Cursor::ZoomOut),
+            ffi::ULCursor_kCursor_Grab => Some(Cursor::Grab),
+            ffi::ULCursor_kCursor_Grabbing => Some(Cursor::Grabbing),
+            ffi::ULCursor_kCursor_Custom => Some(Cursor::Custom),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::console::ConsoleMessage;
+
+use std::{
+    error,
+    ffi::NulError,
+    fmt,
+    path::PathBuf,
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by blyat.
+///
+/// Non-exhaustive: some variants only exist with certain features enabled,
+/// and features are unified across the dependency graph.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The operation needs a view but `Ultralight::view` was never called.
+    NoViewCreated,
+    /// A string handed to Ultralight contained an interior NUL byte.
+    InteriorNul(NulError),
+    /// JavaScript threw while evaluating a script or calling a function.
+    JsException {
+        message: String,
+        line: Option<u32>,
+        column: Option<u32>,
+        source_url: Option<String>,
+        stack: Option<String>,
+    },
+    /// `ulBitmapWritePNG` reported failure for the given path.
+    BitmapWriteFailed(PathBuf),
+    /// A JS value did not have the type the caller asked for.
+    TypeMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// A JS value could not be copied into Rust.
+    Conversion(String),
+    /// Serializing to or deserializing from JSON failed.
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+    /// The page could not be loaded.
+    LoadFailed(String),
+    /// The operation did not complete in time.
+    Timeout,
+    /// The operation was aborted through a `CancellationToken`.
+    Cancelled,
+    /// The page logged errors the `ErrorPolicy` does not allow.
+    ConsoleErrors(Vec<ConsoleMessage>),
+    /// A view cannot have a width or height of 0.
+    InvalidSize {
+        width: u32,
+        height: u32,
+    },
+    /// There is no page to go back or forward to.
+    NoHistoryEntry,
+    /// The document has no height to capture.
+    EmptyDocument,
+    /// No element matches the selector.
+    ElementNotFound(String),
+    /// The element matching the selector has no box or is covered.
+    ElementNotVisible(String),
+    /// The `<select>` has no option with the given value or label.
+    OptionNotFound {
+        selector: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoViewCreated => write!(f, "no view has been created"),
+            Error::InteriorNul(err) => write!(f, "string contains an interior nul byte: {}", err),
+            Error::JsException { message, line, column, source_url, stack } => {
+                write!(f, "javascript exception: {}", message)?;
+
+                if let Some(line) = line {
+                    write!(f, " ({}:{}:{})", source_url.as_deref().unwrap_or("<anonymous>"), line, column.unwrap_or(0))?;
+                }
+
+                if let Some(stack) = stack {
+                    write!(f, "\n{}", stack)?;
+                }
+
+                Ok(())
+            },
+            Error::BitmapWriteFailed(path) => write!(f, "failed to write bitmap to {}", path.display()),
+            Error::TypeMismatch { expected, found } => write!(f, "expected a javascript {}, found {}", expected, found),
+            Error::Conversion(reason) => write!(f, "failed to convert javascript value: {}", reason),
+            #[cfg(feature = "serde")]
+            Error::Json(err) => write!(f, "json error: {}", err),
+            Error::LoadFailed(reason) => write!(f, "failed to load page: {}", reason),
+            Error::Timeout => write!(f, "operation timed out"),
+            Error::Cancelled => write!(f, "operation was cancelled"),
+            Error::ConsoleErrors(messages) => {
+                write!(f, "page logged {} console error(s):", messages.len())?;
+
+                for message in messages {
+                    write!(f, "\n    {}", message)?;
+                }
+
+                Ok(())
+            },
+            Error::InvalidSize { width, height } => write!(f, "invalid view size {}x{}", width, height),
+            Error::NoHistoryEntry => write!(f, "no page to navigate to in the history"),
+            Error::EmptyDocument => write!(f, "the document is empty"),
+            Error::ElementNotFound(selector) => write!(f, "no element matches {}", selector),
+            Error::ElementNotVisible(selector) => write!(f, "element {} is not visible (hidden, empty or covered)", selector),
+            Error::OptionNotFound { selector, value } => write!(f, "{} has no option {:?}", selector, value),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::InteriorNul(err) => Some(err),
+            #[cfg(feature = "serde")]
+            Error::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(err: NulError) -> Error {
+        Error::InteriorNul(err)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Json(err)
+    }
+}
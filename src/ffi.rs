@@ -1,31 +1,5 @@
 /* automatically generated by rust-bindgen */
 
-pub const __DARWIN_C_FULL: u32 = 900000;
-pub const EFAULT: u32 = 14;
-pub const EWOULDBLOCK: u32 = 35;
-pub const EMULTIHOP: u32 = 95;
-pub const EQFULL: u32 = 106;
-pub const ULONG_MAX: i32 = -1;
-pub const ULLONG_MAX: i32 = -1;
-pub const RLIMIT_THREAD_CPULIMITS: u32 = 3;
-pub const WAKEMON_SET_DEFAULTS: u32 = 8;
-pub const IOPOL_DEFAULT: u32 = 0;
-pub const TARGET_OS_SIMULATOR: u32 = 0;
-pub const TARGET_IPHONE_SIMULATOR: u32 = 0;
-pub const MACH_PORT_NULL: u32 = 0;
-pub const MACH_PORT_QLIMIT_DEFAULT: u32 = 5;
-pub const _POSIX_PRIORITY_SCHEDULING: i32 = -1;
-pub const _POSIX_THREAD_PRIORITY_SCHEDULING: i32 = -1;
-pub const _SC_PRIORITY_SCHEDULING: u32 = 35;
-pub const _SC_THREAD_PRIORITY_SCHEDULING: u32 = 89;
-pub const F_ULOCK: u32 = 0;
-pub const SYNC_VOLUME_FULLSYNC: u32 = 1;
-pub const F_FULLFSYNC: u32 = 51;
-pub const DISPATCH_QUEUE_PRIORITY_DEFAULT: u32 = 0;
-pub const KERN_DEFAULT_SET: u32 = 31;
-pub const MACH_MSGH_BITS_CIRCULAR: u32 = 268435456;
-pub const MACH_RCV_TRAILER_NULL: u32 = 0;
-pub const KAUTH_EXTLOOKUP_RESULT: u32 = 1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OpaqueJSContextGroup {
@@ -77,7 +51,6 @@ pub struct OpaqueJSValue {
 pub type JSValueRef = *const OpaqueJSValue;
 pub type JSObjectRef = *mut OpaqueJSValue;
 extern "C" {
-    #[link_name = "\u{1}_JSEvaluateScript"]
     pub fn JSEvaluateScript(
         ctx: JSContextRef,
         script: JSStringRef,
@@ -88,7 +61,6 @@ extern "C" {
     ) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSCheckScriptSyntax"]
     pub fn JSCheckScriptSyntax(
         ctx: JSContextRef,
         script: JSStringRef,
@@ -98,16 +70,8 @@ extern "C" {
     ) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGarbageCollect"]
     pub fn JSGarbageCollect(ctx: JSContextRef);
 }
-pub const QOS_CLASS_USER_INTERACTIVE: _bindgen_ty_37 = 33;
-pub const QOS_CLASS_USER_INITIATED: _bindgen_ty_37 = 25;
-pub const QOS_CLASS_DEFAULT: _bindgen_ty_37 = 21;
-pub const QOS_CLASS_UTILITY: _bindgen_ty_37 = 17;
-pub const QOS_CLASS_BACKGROUND: _bindgen_ty_37 = 9;
-pub const QOS_CLASS_UNSPECIFIED: _bindgen_ty_37 = 0;
-pub type _bindgen_ty_37 = u32;
 pub const JSType_kJSTypeUndefined: JSType = 0;
 pub const JSType_kJSTypeNull: JSType = 1;
 pub const JSType_kJSTypeBoolean: JSType = 2;
@@ -128,35 +92,27 @@ pub const JSTypedArrayType_kJSTypedArrayTypeArrayBuffer: JSTypedArrayType = 9;
 pub const JSTypedArrayType_kJSTypedArrayTypeNone: JSTypedArrayType = 10;
 pub type JSTypedArrayType = u32;
 extern "C" {
-    #[link_name = "\u{1}_JSValueGetType"]
     pub fn JSValueGetType(ctx: JSContextRef, value: JSValueRef) -> JSType;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsUndefined"]
     pub fn JSValueIsUndefined(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsNull"]
     pub fn JSValueIsNull(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsBoolean"]
     pub fn JSValueIsBoolean(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsNumber"]
     pub fn JSValueIsNumber(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsString"]
     pub fn JSValueIsString(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsObject"]
     pub fn JSValueIsObject(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsObjectOfClass"]
     pub fn JSValueIsObjectOfClass(
         ctx: JSContextRef,
         value: JSValueRef,
@@ -164,15 +120,12 @@ extern "C" {
     ) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsArray"]
     pub fn JSValueIsArray(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsDate"]
     pub fn JSValueIsDate(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueGetTypedArrayType"]
     pub fn JSValueGetTypedArrayType(
         ctx: JSContextRef,
         value: JSValueRef,
@@ -180,7 +133,6 @@ extern "C" {
     ) -> JSTypedArrayType;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsEqual"]
     pub fn JSValueIsEqual(
         ctx: JSContextRef,
         a: JSValueRef,
@@ -189,11 +141,9 @@ extern "C" {
     ) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsStrictEqual"]
     pub fn JSValueIsStrictEqual(ctx: JSContextRef, a: JSValueRef, b: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueIsInstanceOfConstructor"]
     pub fn JSValueIsInstanceOfConstructor(
         ctx: JSContextRef,
         value: JSValueRef,
@@ -202,31 +152,24 @@ extern "C" {
     ) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueMakeUndefined"]
     pub fn JSValueMakeUndefined(ctx: JSContextRef) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueMakeNull"]
     pub fn JSValueMakeNull(ctx: JSContextRef) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueMakeBoolean"]
     pub fn JSValueMakeBoolean(ctx: JSContextRef, boolean: bool) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueMakeNumber"]
     pub fn JSValueMakeNumber(ctx: JSContextRef, number: f64) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueMakeString"]
     pub fn JSValueMakeString(ctx: JSContextRef, string: JSStringRef) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueMakeFromJSONString"]
     pub fn JSValueMakeFromJSONString(ctx: JSContextRef, string: JSStringRef) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueCreateJSONString"]
     pub fn JSValueCreateJSONString(
         ctx: JSContextRef,
         value: JSValueRef,
@@ -235,16 +178,13 @@ extern "C" {
     ) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueToBoolean"]
     pub fn JSValueToBoolean(ctx: JSContextRef, value: JSValueRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueToNumber"]
     pub fn JSValueToNumber(ctx: JSContextRef, value: JSValueRef, exception: *mut JSValueRef)
         -> f64;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueToStringCopy"]
     pub fn JSValueToStringCopy(
         ctx: JSContextRef,
         value: JSValueRef,
@@ -252,7 +192,6 @@ extern "C" {
     ) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueToObject"]
     pub fn JSValueToObject(
         ctx: JSContextRef,
         value: JSValueRef,
@@ -260,11 +199,9 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueProtect"]
     pub fn JSValueProtect(ctx: JSContextRef, value: JSValueRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSValueUnprotect"]
     pub fn JSValueUnprotect(ctx: JSContextRef, value: JSValueRef);
 }
 pub const kJSPropertyAttributeNone: _bindgen_ty_64 = 0;
@@ -372,7 +309,7 @@ fn bindgen_test_layout_JSStaticValue() {
         concat!("Alignment of ", stringify!(JSStaticValue))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticValue>())).name as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticValue, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -382,7 +319,7 @@ fn bindgen_test_layout_JSStaticValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticValue>())).getProperty as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticValue, getProperty),
         8usize,
         concat!(
             "Offset of field: ",
@@ -392,7 +329,7 @@ fn bindgen_test_layout_JSStaticValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticValue>())).setProperty as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticValue, setProperty),
         16usize,
         concat!(
             "Offset of field: ",
@@ -402,7 +339,7 @@ fn bindgen_test_layout_JSStaticValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticValue>())).attributes as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticValue, attributes),
         24usize,
         concat!(
             "Offset of field: ",
@@ -432,7 +369,7 @@ fn bindgen_test_layout_JSStaticFunction() {
         concat!("Alignment of ", stringify!(JSStaticFunction))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticFunction>())).name as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticFunction, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -442,7 +379,7 @@ fn bindgen_test_layout_JSStaticFunction() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticFunction>())).callAsFunction as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticFunction, callAsFunction),
         8usize,
         concat!(
             "Offset of field: ",
@@ -452,7 +389,7 @@ fn bindgen_test_layout_JSStaticFunction() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSStaticFunction>())).attributes as *const _ as usize },
+        ::std::mem::offset_of!(JSStaticFunction, attributes),
         16usize,
         concat!(
             "Offset of field: ",
@@ -496,7 +433,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         concat!("Alignment of ", stringify!(JSClassDefinition))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).version as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, version),
         0usize,
         concat!(
             "Offset of field: ",
@@ -506,7 +443,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).attributes as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, attributes),
         4usize,
         concat!(
             "Offset of field: ",
@@ -516,7 +453,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).className as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, className),
         8usize,
         concat!(
             "Offset of field: ",
@@ -526,7 +463,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).parentClass as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, parentClass),
         16usize,
         concat!(
             "Offset of field: ",
@@ -536,7 +473,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).staticValues as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, staticValues),
         24usize,
         concat!(
             "Offset of field: ",
@@ -546,9 +483,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<JSClassDefinition>())).staticFunctions as *const _ as usize
-        },
+        ::std::mem::offset_of!(JSClassDefinition, staticFunctions),
         32usize,
         concat!(
             "Offset of field: ",
@@ -558,7 +493,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).initialize as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, initialize),
         40usize,
         concat!(
             "Offset of field: ",
@@ -568,7 +503,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).finalize as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, finalize),
         48usize,
         concat!(
             "Offset of field: ",
@@ -578,7 +513,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).hasProperty as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, hasProperty),
         56usize,
         concat!(
             "Offset of field: ",
@@ -588,7 +523,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).getProperty as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, getProperty),
         64usize,
         concat!(
             "Offset of field: ",
@@ -598,7 +533,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).setProperty as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, setProperty),
         72usize,
         concat!(
             "Offset of field: ",
@@ -608,9 +543,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<JSClassDefinition>())).deleteProperty as *const _ as usize
-        },
+        ::std::mem::offset_of!(JSClassDefinition, deleteProperty),
         80usize,
         concat!(
             "Offset of field: ",
@@ -620,9 +553,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<JSClassDefinition>())).getPropertyNames as *const _ as usize
-        },
+        ::std::mem::offset_of!(JSClassDefinition, getPropertyNames),
         88usize,
         concat!(
             "Offset of field: ",
@@ -632,9 +563,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<JSClassDefinition>())).callAsFunction as *const _ as usize
-        },
+        ::std::mem::offset_of!(JSClassDefinition, callAsFunction),
         96usize,
         concat!(
             "Offset of field: ",
@@ -644,9 +573,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<JSClassDefinition>())).callAsConstructor as *const _ as usize
-        },
+        ::std::mem::offset_of!(JSClassDefinition, callAsConstructor),
         104usize,
         concat!(
             "Offset of field: ",
@@ -656,7 +583,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).hasInstance as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, hasInstance),
         112usize,
         concat!(
             "Offset of field: ",
@@ -666,7 +593,7 @@ fn bindgen_test_layout_JSClassDefinition() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<JSClassDefinition>())).convertToType as *const _ as usize },
+        ::std::mem::offset_of!(JSClassDefinition, convertToType),
         120usize,
         concat!(
             "Offset of field: ",
@@ -677,23 +604,18 @@ fn bindgen_test_layout_JSClassDefinition() {
     );
 }
 extern "C" {
-    #[link_name = "\u{1}_kJSClassDefinitionEmpty"]
     pub static kJSClassDefinitionEmpty: JSClassDefinition;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSClassCreate"]
     pub fn JSClassCreate(definition: *const JSClassDefinition) -> JSClassRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSClassRetain"]
     pub fn JSClassRetain(jsClass: JSClassRef) -> JSClassRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSClassRelease"]
     pub fn JSClassRelease(jsClass: JSClassRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMake"]
     pub fn JSObjectMake(
         ctx: JSContextRef,
         jsClass: JSClassRef,
@@ -701,7 +623,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeFunctionWithCallback"]
     pub fn JSObjectMakeFunctionWithCallback(
         ctx: JSContextRef,
         name: JSStringRef,
@@ -709,7 +630,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeConstructor"]
     pub fn JSObjectMakeConstructor(
         ctx: JSContextRef,
         jsClass: JSClassRef,
@@ -717,7 +637,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeArray"]
     pub fn JSObjectMakeArray(
         ctx: JSContextRef,
         argumentCount: usize,
@@ -726,7 +645,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeDate"]
     pub fn JSObjectMakeDate(
         ctx: JSContextRef,
         argumentCount: usize,
@@ -735,7 +653,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeError"]
     pub fn JSObjectMakeError(
         ctx: JSContextRef,
         argumentCount: usize,
@@ -744,7 +661,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeRegExp"]
     pub fn JSObjectMakeRegExp(
         ctx: JSContextRef,
         argumentCount: usize,
@@ -753,7 +669,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeFunction"]
     pub fn JSObjectMakeFunction(
         ctx: JSContextRef,
         name: JSStringRef,
@@ -766,15 +681,12 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetPrototype"]
     pub fn JSObjectGetPrototype(ctx: JSContextRef, object: JSObjectRef) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectSetPrototype"]
     pub fn JSObjectSetPrototype(ctx: JSContextRef, object: JSObjectRef, value: JSValueRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectHasProperty"]
     pub fn JSObjectHasProperty(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -782,7 +694,6 @@ extern "C" {
     ) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetProperty"]
     pub fn JSObjectGetProperty(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -791,7 +702,6 @@ extern "C" {
     ) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectSetProperty"]
     pub fn JSObjectSetProperty(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -802,7 +712,6 @@ extern "C" {
     );
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectDeleteProperty"]
     pub fn JSObjectDeleteProperty(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -811,7 +720,6 @@ extern "C" {
     ) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetPropertyAtIndex"]
     pub fn JSObjectGetPropertyAtIndex(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -820,7 +728,6 @@ extern "C" {
     ) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectSetPropertyAtIndex"]
     pub fn JSObjectSetPropertyAtIndex(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -830,19 +737,15 @@ extern "C" {
     );
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetPrivate"]
     pub fn JSObjectGetPrivate(object: JSObjectRef) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectSetPrivate"]
     pub fn JSObjectSetPrivate(object: JSObjectRef, data: *mut ::std::os::raw::c_void) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectIsFunction"]
     pub fn JSObjectIsFunction(ctx: JSContextRef, object: JSObjectRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectCallAsFunction"]
     pub fn JSObjectCallAsFunction(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -853,11 +756,9 @@ extern "C" {
     ) -> JSValueRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectIsConstructor"]
     pub fn JSObjectIsConstructor(ctx: JSContextRef, object: JSObjectRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectCallAsConstructor"]
     pub fn JSObjectCallAsConstructor(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -867,120 +768,94 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectCopyPropertyNames"]
     pub fn JSObjectCopyPropertyNames(
         ctx: JSContextRef,
         object: JSObjectRef,
     ) -> JSPropertyNameArrayRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSPropertyNameArrayRetain"]
     pub fn JSPropertyNameArrayRetain(array: JSPropertyNameArrayRef) -> JSPropertyNameArrayRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSPropertyNameArrayRelease"]
     pub fn JSPropertyNameArrayRelease(array: JSPropertyNameArrayRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSPropertyNameArrayGetCount"]
     pub fn JSPropertyNameArrayGetCount(array: JSPropertyNameArrayRef) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSPropertyNameArrayGetNameAtIndex"]
     pub fn JSPropertyNameArrayGetNameAtIndex(
         array: JSPropertyNameArrayRef,
         index: usize,
     ) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSPropertyNameAccumulatorAddName"]
     pub fn JSPropertyNameAccumulatorAddName(
         accumulator: JSPropertyNameAccumulatorRef,
         propertyName: JSStringRef,
     );
 }
 extern "C" {
-    #[link_name = "\u{1}_JSContextGroupCreate"]
     pub fn JSContextGroupCreate() -> JSContextGroupRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSContextGroupRetain"]
     pub fn JSContextGroupRetain(group: JSContextGroupRef) -> JSContextGroupRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSContextGroupRelease"]
     pub fn JSContextGroupRelease(group: JSContextGroupRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGlobalContextCreate"]
     pub fn JSGlobalContextCreate(globalObjectClass: JSClassRef) -> JSGlobalContextRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGlobalContextCreateInGroup"]
     pub fn JSGlobalContextCreateInGroup(
         group: JSContextGroupRef,
         globalObjectClass: JSClassRef,
     ) -> JSGlobalContextRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGlobalContextRetain"]
     pub fn JSGlobalContextRetain(ctx: JSGlobalContextRef) -> JSGlobalContextRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGlobalContextRelease"]
     pub fn JSGlobalContextRelease(ctx: JSGlobalContextRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSContextGetGlobalObject"]
     pub fn JSContextGetGlobalObject(ctx: JSContextRef) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSContextGetGroup"]
     pub fn JSContextGetGroup(ctx: JSContextRef) -> JSContextGroupRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSContextGetGlobalContext"]
     pub fn JSContextGetGlobalContext(ctx: JSContextRef) -> JSGlobalContextRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGlobalContextCopyName"]
     pub fn JSGlobalContextCopyName(ctx: JSGlobalContextRef) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSGlobalContextSetName"]
     pub fn JSGlobalContextSetName(ctx: JSGlobalContextRef, name: JSStringRef);
 }
 pub type JSChar = ::std::os::raw::c_ushort;
 extern "C" {
-    #[link_name = "\u{1}_JSStringCreateWithCharacters"]
     pub fn JSStringCreateWithCharacters(chars: *const JSChar, numChars: usize) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringCreateWithUTF8CString"]
     pub fn JSStringCreateWithUTF8CString(string: *const ::std::os::raw::c_char) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringRetain"]
     pub fn JSStringRetain(string: JSStringRef) -> JSStringRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringRelease"]
     pub fn JSStringRelease(string: JSStringRef);
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringGetLength"]
     pub fn JSStringGetLength(string: JSStringRef) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringGetCharactersPtr"]
     pub fn JSStringGetCharactersPtr(string: JSStringRef) -> *const JSChar;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringGetMaximumUTF8CStringSize"]
     pub fn JSStringGetMaximumUTF8CStringSize(string: JSStringRef) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringGetUTF8CString"]
     pub fn JSStringGetUTF8CString(
         string: JSStringRef,
         buffer: *mut ::std::os::raw::c_char,
@@ -988,15 +863,12 @@ extern "C" {
     ) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringIsEqual"]
     pub fn JSStringIsEqual(a: JSStringRef, b: JSStringRef) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSStringIsEqualToUTF8CString"]
     pub fn JSStringIsEqualToUTF8CString(a: JSStringRef, b: *const ::std::os::raw::c_char) -> bool;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeTypedArray"]
     pub fn JSObjectMakeTypedArray(
         ctx: JSContextRef,
         arrayType: JSTypedArrayType,
@@ -1005,7 +877,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeTypedArrayWithBytesNoCopy"]
     pub fn JSObjectMakeTypedArrayWithBytesNoCopy(
         ctx: JSContextRef,
         arrayType: JSTypedArrayType,
@@ -1017,7 +888,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeTypedArrayWithArrayBuffer"]
     pub fn JSObjectMakeTypedArrayWithArrayBuffer(
         ctx: JSContextRef,
         arrayType: JSTypedArrayType,
@@ -1026,7 +896,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeTypedArrayWithArrayBufferAndOffset"]
     pub fn JSObjectMakeTypedArrayWithArrayBufferAndOffset(
         ctx: JSContextRef,
         arrayType: JSTypedArrayType,
@@ -1037,7 +906,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetTypedArrayBytesPtr"]
     pub fn JSObjectGetTypedArrayBytesPtr(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1045,7 +913,6 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetTypedArrayLength"]
     pub fn JSObjectGetTypedArrayLength(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1053,7 +920,6 @@ extern "C" {
     ) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetTypedArrayByteLength"]
     pub fn JSObjectGetTypedArrayByteLength(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1061,7 +927,6 @@ extern "C" {
     ) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetTypedArrayByteOffset"]
     pub fn JSObjectGetTypedArrayByteOffset(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1069,7 +934,6 @@ extern "C" {
     ) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetTypedArrayBuffer"]
     pub fn JSObjectGetTypedArrayBuffer(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1077,7 +941,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectMakeArrayBufferWithBytesNoCopy"]
     pub fn JSObjectMakeArrayBufferWithBytesNoCopy(
         ctx: JSContextRef,
         bytes: *mut ::std::os::raw::c_void,
@@ -1088,7 +951,6 @@ extern "C" {
     ) -> JSObjectRef;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetArrayBufferBytesPtr"]
     pub fn JSObjectGetArrayBufferBytesPtr(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1096,7 +958,6 @@ extern "C" {
     ) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[link_name = "\u{1}_JSObjectGetArrayBufferByteLength"]
     pub fn JSObjectGetArrayBufferByteLength(
         ctx: JSContextRef,
         object: JSObjectRef,
@@ -1249,87 +1110,70 @@ pub const ULScrollEventType_kScrollEventType_ScrollByPage: ULScrollEventType = 1
 pub type ULScrollEventType = u32;
 extern "C" {
     #[doc = " Create config with default values (see <Ultralight/platform/Config.h>)."]
-    #[link_name = "\u{1}_ulCreateConfig"]
     pub fn ulCreateConfig() -> ULConfig;
 }
 extern "C" {
     #[doc = " Destroy config."]
-    #[link_name = "\u{1}_ulDestroyConfig"]
     pub fn ulDestroyConfig(config: ULConfig);
 }
 extern "C" {
     #[doc = " Set whether images should be enabled (Default = True)"]
-    #[link_name = "\u{1}_ulConfigSetEnableImages"]
     pub fn ulConfigSetEnableImages(config: ULConfig, enabled: bool);
 }
 extern "C" {
     #[doc = " Set whether JavaScript should be eanbled (Default = True)"]
-    #[link_name = "\u{1}_ulConfigSetEnableJavaScript"]
     pub fn ulConfigSetEnableJavaScript(config: ULConfig, enabled: bool);
 }
 extern "C" {
     #[doc = " Set whether we should use BGRA byte order (instead of RGBA) for View bitmaps. (Default = False)"]
-    #[link_name = "\u{1}_ulConfigSetUseBGRAForOffscreenRendering"]
     pub fn ulConfigSetUseBGRAForOffscreenRendering(config: ULConfig, enabled: bool);
 }
 extern "C" {
     #[doc = " Set the amount that the application DPI has been scaled, used for oversampling raster shapes. (Default = 1.0)"]
-    #[link_name = "\u{1}_ulConfigSetDeviceScaleHint"]
     pub fn ulConfigSetDeviceScaleHint(config: ULConfig, value: f64);
 }
 extern "C" {
     #[doc = " Set default font-family to use (Default = Times New Roman)"]
-    #[link_name = "\u{1}_ulConfigSetFontFamilyStandard"]
     pub fn ulConfigSetFontFamilyStandard(config: ULConfig, font_name: ULString);
 }
 extern "C" {
     #[doc = " Set default font-family to use for fixed fonts, eg <pre> and <code>. (Default = Courier New)"]
-    #[link_name = "\u{1}_ulConfigSetFontFamilyFixed"]
     pub fn ulConfigSetFontFamilyFixed(config: ULConfig, font_name: ULString);
 }
 extern "C" {
     #[doc = " Set default font-family to use for serif fonts. (Default = Times New Roman)"]
-    #[link_name = "\u{1}_ulConfigSetFontFamilySerif"]
     pub fn ulConfigSetFontFamilySerif(config: ULConfig, font_name: ULString);
 }
 extern "C" {
     #[doc = " Set default font-family to use for sans-serif fonts. (Default = Arial)"]
-    #[link_name = "\u{1}_ulConfigSetFontFamilySansSerif"]
     pub fn ulConfigSetFontFamilySansSerif(config: ULConfig, font_name: ULString);
 }
 extern "C" {
     #[doc = " Set user agent string. (See <Ultralight/platform/Config.h> for the default)"]
-    #[link_name = "\u{1}_ulConfigSetUserAgent"]
     pub fn ulConfigSetUserAgent(config: ULConfig, agent_string: ULString);
 }
 extern "C" {
     #[doc = " Set user stylesheet (CSS). (Default = Empty)"]
-    #[link_name = "\u{1}_ulConfigSetUserStylesheet"]
     pub fn ulConfigSetUserStylesheet(config: ULConfig, css_string: ULString);
 }
 extern "C" {
     #[doc = " Create renderer (you should create this only once per application lifetime)."]
-    #[link_name = "\u{1}_ulCreateRenderer"]
     pub fn ulCreateRenderer(config: ULConfig) -> ULRenderer;
 }
 extern "C" {
     #[doc = " Destroy renderer."]
-    #[link_name = "\u{1}_ulDestroyRenderer"]
     pub fn ulDestroyRenderer(renderer: ULRenderer);
 }
 extern "C" {
     #[doc = " Update timers and dispatch internal callbacks (JavaScript and network)"]
-    #[link_name = "\u{1}_ulUpdate"]
     pub fn ulUpdate(renderer: ULRenderer);
 }
 extern "C" {
     #[doc = " Render all active Views to their respective bitmaps."]
-    #[link_name = "\u{1}_ulRender"]
     pub fn ulRender(renderer: ULRenderer);
 }
 extern "C" {
     #[doc = " Create a View with certain size."]
-    #[link_name = "\u{1}_ulCreateView"]
     pub fn ulCreateView(
         renderer: ULRenderer,
         width: ::std::os::raw::c_uint,
@@ -1339,47 +1183,38 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Destroy a View."]
-    #[link_name = "\u{1}_ulDestroyView"]
     pub fn ulDestroyView(view: ULView);
 }
 extern "C" {
     #[doc = " Get current URL (don\'t destroy the returned string, it is owned by the View and has same lifetime as View)."]
-    #[link_name = "\u{1}_ulViewGetURL"]
     pub fn ulViewGetURL(view: ULView) -> ULString;
 }
 extern "C" {
     #[doc = " Get current title (don\'t destroy the returned string, it is owned by the View and has same lifetime as View)."]
-    #[link_name = "\u{1}_ulViewGetTitle"]
     pub fn ulViewGetTitle(view: ULView) -> ULString;
 }
 extern "C" {
     #[doc = " Check if main frame is loading."]
-    #[link_name = "\u{1}_ulViewIsLoading"]
     pub fn ulViewIsLoading(view: ULView) -> bool;
 }
 extern "C" {
     #[doc = " Check if bitmap is dirty (has changed since last call to ulViewGetBitmap())"]
-    #[link_name = "\u{1}_ulViewIsBitmapDirty"]
     pub fn ulViewIsBitmapDirty(view: ULView) -> bool;
 }
 extern "C" {
     #[doc = " Get bitmap (don\'t destroy the returned bitmap, it is owned by the View and has same lifetime as View)."]
-    #[link_name = "\u{1}_ulViewGetBitmap"]
     pub fn ulViewGetBitmap(view: ULView) -> ULBitmap;
 }
 extern "C" {
     #[doc = " Load a raw string of html"]
-    #[link_name = "\u{1}_ulViewLoadHTML"]
     pub fn ulViewLoadHTML(view: ULView, html_string: ULString);
 }
 extern "C" {
     #[doc = " Load a URL into main frame"]
-    #[link_name = "\u{1}_ulViewLoadURL"]
     pub fn ulViewLoadURL(view: ULView, url_string: ULString);
 }
 extern "C" {
     #[doc = " Resize view to a certain width and height"]
-    #[link_name = "\u{1}_ulViewResize"]
     pub fn ulViewResize(
         view: ULView,
         width: ::std::os::raw::c_uint,
@@ -1388,62 +1223,50 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Get the page\'s JSContext for use with JavaScriptCore API"]
-    #[link_name = "\u{1}_ulViewGetJSContext"]
     pub fn ulViewGetJSContext(view: ULView) -> JSContextRef;
 }
 extern "C" {
     #[doc = " Evaluate a raw string of JavaScript and return result"]
-    #[link_name = "\u{1}_ulViewEvaluateScript"]
     pub fn ulViewEvaluateScript(view: ULView, js_string: ULString) -> JSValueRef;
 }
 extern "C" {
     #[doc = " Check if can navigate backwards in history"]
-    #[link_name = "\u{1}_ulViewCanGoBack"]
     pub fn ulViewCanGoBack(view: ULView) -> bool;
 }
 extern "C" {
     #[doc = " Check if can navigate forwards in history"]
-    #[link_name = "\u{1}_ulViewCanGoForward"]
     pub fn ulViewCanGoForward(view: ULView) -> bool;
 }
 extern "C" {
     #[doc = " Navigate backwards in history"]
-    #[link_name = "\u{1}_ulViewGoBack"]
     pub fn ulViewGoBack(view: ULView);
 }
 extern "C" {
     #[doc = " Navigate forwards in history"]
-    #[link_name = "\u{1}_ulViewGoForward"]
     pub fn ulViewGoForward(view: ULView);
 }
 extern "C" {
     #[doc = " Navigate to arbitrary offset in history"]
-    #[link_name = "\u{1}_ulViewGoToHistoryOffset"]
     pub fn ulViewGoToHistoryOffset(view: ULView, offset: ::std::os::raw::c_int);
 }
 extern "C" {
     #[doc = " Reload current page"]
-    #[link_name = "\u{1}_ulViewReload"]
     pub fn ulViewReload(view: ULView);
 }
 extern "C" {
     #[doc = " Stop all page loads"]
-    #[link_name = "\u{1}_ulViewStop"]
     pub fn ulViewStop(view: ULView);
 }
 extern "C" {
     #[doc = " Fire a keyboard event"]
-    #[link_name = "\u{1}_ulViewFireKeyEvent"]
     pub fn ulViewFireKeyEvent(view: ULView, key_event: ULKeyEvent);
 }
 extern "C" {
     #[doc = " Fire a mouse event"]
-    #[link_name = "\u{1}_ulViewFireMouseEvent"]
     pub fn ulViewFireMouseEvent(view: ULView, mouse_event: ULMouseEvent);
 }
 extern "C" {
     #[doc = " Fire a scroll event"]
-    #[link_name = "\u{1}_ulViewFireScrollEvent"]
     pub fn ulViewFireScrollEvent(view: ULView, scroll_event: ULScrollEvent);
 }
 pub type ULChangeTitleCallback = ::std::option::Option<
@@ -1451,7 +1274,6 @@ pub type ULChangeTitleCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the page title changes"]
-    #[link_name = "\u{1}_ulViewSetChangeTitleCallback"]
     pub fn ulViewSetChangeTitleCallback(
         view: ULView,
         callback: ULChangeTitleCallback,
@@ -1463,7 +1285,6 @@ pub type ULChangeURLCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the page URL changes"]
-    #[link_name = "\u{1}_ulViewSetChangeURLCallback"]
     pub fn ulViewSetChangeURLCallback(
         view: ULView,
         callback: ULChangeURLCallback,
@@ -1475,7 +1296,6 @@ pub type ULChangeTooltipCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the tooltip changes (usually as result of a mouse hover)"]
-    #[link_name = "\u{1}_ulViewSetChangeTooltipCallback"]
     pub fn ulViewSetChangeTooltipCallback(
         view: ULView,
         callback: ULChangeTooltipCallback,
@@ -1487,7 +1307,6 @@ pub type ULChangeCursorCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the mouse cursor changes"]
-    #[link_name = "\u{1}_ulViewSetChangeCursorCallback"]
     pub fn ulViewSetChangeCursorCallback(
         view: ULView,
         callback: ULChangeCursorCallback,
@@ -1508,7 +1327,6 @@ pub type ULAddConsoleMessageCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when a message is added to the console (useful for JavaScript / network errors and debugging)"]
-    #[link_name = "\u{1}_ulViewSetAddConsoleMessageCallback"]
     pub fn ulViewSetAddConsoleMessageCallback(
         view: ULView,
         callback: ULAddConsoleMessageCallback,
@@ -1520,7 +1338,6 @@ pub type ULBeginLoadingCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the page begins loading new URL into main frame"]
-    #[link_name = "\u{1}_ulViewSetBeginLoadingCallback"]
     pub fn ulViewSetBeginLoadingCallback(
         view: ULView,
         callback: ULBeginLoadingCallback,
@@ -1532,7 +1349,6 @@ pub type ULFinishLoadingCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the page finishes loading URL into main frame"]
-    #[link_name = "\u{1}_ulViewSetFinishLoadingCallback"]
     pub fn ulViewSetFinishLoadingCallback(
         view: ULView,
         callback: ULFinishLoadingCallback,
@@ -1544,7 +1360,6 @@ pub type ULUpdateHistoryCallback = ::std::option::Option<
 >;
 extern "C" {
     #[doc = " Set callback for when the history (back/forward state) is modified"]
-    #[link_name = "\u{1}_ulViewSetUpdateHistoryCallback"]
     pub fn ulViewSetUpdateHistoryCallback(
         view: ULView,
         callback: ULUpdateHistoryCallback,
@@ -1557,7 +1372,6 @@ pub type ULDOMReadyCallback = ::std::option::Option<
 extern "C" {
     #[doc = " Set callback for when all JavaScript has been parsed and the document is ready."]
     #[doc = " This is the best time to make any initial JavaScript calls to your page."]
-    #[link_name = "\u{1}_ulViewSetDOMReadyCallback"]
     pub fn ulViewSetDOMReadyCallback(
         view: ULView,
         callback: ULDOMReadyCallback,
@@ -1566,47 +1380,38 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Create string from null-terminated ASCII C-string"]
-    #[link_name = "\u{1}_ulCreateString"]
     pub fn ulCreateString(str: *const ::std::os::raw::c_char) -> ULString;
 }
 extern "C" {
     #[doc = " Create string from UTF-8 buffer"]
-    #[link_name = "\u{1}_ulCreateStringUTF8"]
     pub fn ulCreateStringUTF8(str: *const ::std::os::raw::c_char, len: usize) -> ULString;
 }
 extern "C" {
     #[doc = " Create string from UTF-16 buffer"]
-    #[link_name = "\u{1}_ulCreateStringUTF16"]
     pub fn ulCreateStringUTF16(str: *mut ULChar16, len: usize) -> ULString;
 }
 extern "C" {
     #[doc = " Destroy string (you should destroy any strings you explicitly Create)."]
-    #[link_name = "\u{1}_ulDestroyString"]
     pub fn ulDestroyString(str: ULString);
 }
 extern "C" {
     #[doc = " Get internal UTF-16 buffer data."]
-    #[link_name = "\u{1}_ulStringGetData"]
     pub fn ulStringGetData(str: ULString) -> *mut ULChar16;
 }
 extern "C" {
     #[doc = " Get length in UTF-16 characters"]
-    #[link_name = "\u{1}_ulStringGetLength"]
     pub fn ulStringGetLength(str: ULString) -> usize;
 }
 extern "C" {
     #[doc = " Whether this string is empty or not."]
-    #[link_name = "\u{1}_ulStringIsEmpty"]
     pub fn ulStringIsEmpty(str: ULString) -> bool;
 }
 extern "C" {
     #[doc = " Create empty bitmap."]
-    #[link_name = "\u{1}_ulCreateEmptyBitmap"]
     pub fn ulCreateEmptyBitmap() -> ULBitmap;
 }
 extern "C" {
     #[doc = " Create bitmap with certain dimensions and pixel format."]
-    #[link_name = "\u{1}_ulCreateBitmap"]
     pub fn ulCreateBitmap(
         width: ::std::os::raw::c_uint,
         height: ::std::os::raw::c_uint,
@@ -1615,7 +1420,6 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Create bitmap from existing pixel buffer."]
-    #[link_name = "\u{1}_ulCreateBitmapFromPixels"]
     pub fn ulCreateBitmapFromPixels(
         width: ::std::os::raw::c_uint,
         height: ::std::os::raw::c_uint,
@@ -1628,74 +1432,58 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Create bitmap from copy."]
-    #[link_name = "\u{1}_ulCreateBitmapFromCopy"]
     pub fn ulCreateBitmapFromCopy(existing_bitmap: ULBitmap) -> ULBitmap;
 }
 extern "C" {
     #[doc = " Destroy a bitmap (you should only destroy Bitmaps you have explicitly created via ulCreateBitmapXXX())"]
-    #[link_name = "\u{1}_ulDestroyBitmap"]
     pub fn ulDestroyBitmap(bitmap: ULBitmap);
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapGetWidth"]
     pub fn ulBitmapGetWidth(bitmap: ULBitmap) -> ::std::os::raw::c_uint;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapGetHeight"]
     pub fn ulBitmapGetHeight(bitmap: ULBitmap) -> ::std::os::raw::c_uint;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapGetFormat"]
     pub fn ulBitmapGetFormat(bitmap: ULBitmap) -> ULBitmapFormat;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapGetBpp"]
     pub fn ulBitmapGetBpp(bitmap: ULBitmap) -> ::std::os::raw::c_uint;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapGetRowBytes"]
     pub fn ulBitmapGetRowBytes(bitmap: ULBitmap) -> ::std::os::raw::c_uint;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapGetSize"]
     pub fn ulBitmapGetSize(bitmap: ULBitmap) -> usize;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapOwnsPixels"]
     pub fn ulBitmapOwnsPixels(bitmap: ULBitmap) -> bool;
 }
 extern "C" {
     #[doc = " Lock pixels for reading/writing, returns pointer to pixel buffer."]
-    #[link_name = "\u{1}_ulBitmapLockPixels"]
     pub fn ulBitmapLockPixels(bitmap: ULBitmap) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
     #[doc = " Unlock pixels after locking."]
-    #[link_name = "\u{1}_ulBitmapUnlockPixels"]
     pub fn ulBitmapUnlockPixels(bitmap: ULBitmap);
 }
 extern "C" {
     #[doc = " Get raw pixel buffer-- you should only call this if Bitmap is already locked."]
-    #[link_name = "\u{1}_ulBitmapRawPixels"]
     pub fn ulBitmapRawPixels(bitmap: ULBitmap) -> *mut ::std::os::raw::c_void;
 }
 extern "C" {
-    #[link_name = "\u{1}_ulBitmapIsEmpty"]
     pub fn ulBitmapIsEmpty(bitmap: ULBitmap) -> bool;
 }
 extern "C" {
     #[doc = " Reset bitmap pixels to 0."]
-    #[link_name = "\u{1}_ulBitmapErase"]
     pub fn ulBitmapErase(bitmap: ULBitmap);
 }
 extern "C" {
     #[doc = " Write bitmap to a PNG on disk."]
-    #[link_name = "\u{1}_ulBitmapWritePNG"]
     pub fn ulBitmapWritePNG(bitmap: ULBitmap, path: *const ::std::os::raw::c_char) -> bool;
 }
 extern "C" {
     #[doc = " Create a key event, see <Ultralight/KeyEvent.h> for help with the following parameters."]
-    #[link_name = "\u{1}_ulCreateKeyEvent"]
     pub fn ulCreateKeyEvent(
         type_: ULKeyEventType,
         modifiers: ::std::os::raw::c_uint,
@@ -1710,12 +1498,10 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Destroy a key event."]
-    #[link_name = "\u{1}_ulDestroyKeyEvent"]
     pub fn ulDestroyKeyEvent(evt: ULKeyEvent);
 }
 extern "C" {
     #[doc = " Create a mouse event, see <Ultralight/MouseEvent.h> for help with the following parameters."]
-    #[link_name = "\u{1}_ulCreateMouseEvent"]
     pub fn ulCreateMouseEvent(
         type_: ULMouseEventType,
         x: ::std::os::raw::c_int,
@@ -1725,12 +1511,10 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Destroy a mouse event."]
-    #[link_name = "\u{1}_ulDestroyMouseEvent"]
     pub fn ulDestroyMouseEvent(evt: ULMouseEvent);
 }
 extern "C" {
     #[doc = " Create a scroll event, see <Ultralight/ScrollEvent.h> for help with the following parameters."]
-    #[link_name = "\u{1}_ulCreateScrollEvent"]
     pub fn ulCreateScrollEvent(
         type_: ULScrollEventType,
         delta_x: ::std::os::raw::c_int,
@@ -1739,6 +1523,5 @@ extern "C" {
 }
 extern "C" {
     #[doc = " Destroy a scroll event."]
-    #[link_name = "\u{1}_ulDestroyScrollEvent"]
     pub fn ulDestroyScrollEvent(evt: ULScrollEvent);
 }
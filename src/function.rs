@@ -0,0 +1,139 @@
+use crate::{
+    ffi,
+    helpers,
+    helpers_internal::throw_js_error,
+    value::{
+        FromJs,
+        JsValue,
+        ToJs,
+    },
+    Result,
+    Ultralight,
+};
+
+/// Arguments of a JS call, converted to `JsValue` only when asked for.
+///
+/// Page objects such as DOM nodes or events are cyclic and cannot be
+/// copied into Rust, so only the arguments a function uses are converted.
+pub struct JsArgs<'a> {
+    ctx: ffi::JSContextRef,
+    values: &'a [ffi::JSValueRef],
+}
+
+impl<'a> JsArgs<'a> {
+    /// `values` must be live values of `ctx`.
+    pub(crate) unsafe fn new(ctx: ffi::JSContextRef, values: &'a [ffi::JSValueRef]) -> JsArgs<'a> {
+        JsArgs { ctx, values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Convert the argument at `index`; missing arguments are `undefined`.
+    pub fn get(&self, index: usize) -> Result<JsValue> {
+        match self.values.get(index) {
+            Some(value) => unsafe { JsValue::from_raw(self.ctx, *value) },
+            None => Ok(JsValue::Undefined),
+        }
+    }
+}
+
+/// Conversion from the argument list of a JS call.
+///
+/// Implemented for tuples of `FromJs` types, where missing arguments are
+/// passed as `undefined` (so `Option<T>` makes a parameter optional) and
+/// surplus arguments are ignored without being converted, and for `Vec<T>`
+/// to take all arguments.
+pub trait FromJsArgs: Sized {
+    fn from_js_args(args: &JsArgs) -> Result<Self>;
+}
+
+impl<T: FromJs> FromJsArgs for Vec<T> {
+    fn from_js_args(args: &JsArgs) -> Result<Vec<T>> {
+        (0..args.len()).map(|index| T::from_js(args.get(index)?)).collect()
+    }
+}
+
+macro_rules! from_js_args_tuple (
+    ($($name:ident),*) => (
+        impl<$($name: FromJs),*> FromJsArgs for ($($name,)*) {
+            #[allow(unused_mut, unused_variables)]
+            fn from_js_args(args: &JsArgs) -> Result<Self> {
+                let mut index = 0;
+
+                Ok(($(
+                    $name::from_js({
+                        index += 1;
+                        args.get(index - 1)?
+                    })?,
+                )*))
+            }
+        }
+    )
+);
+
+from_js_args_tuple!();
+from_js_args_tuple!(A);
+from_js_args_tuple!(A, B);
+from_js_args_tuple!(A, B, C);
+from_js_args_tuple!(A, B, C, D);
+from_js_args_tuple!(A, B, C, D, E);
+from_js_args_tuple!(A, B, C, D, E, F);
+from_js_args_tuple!(A, B, C, D, E, F, G);
+from_js_args_tuple!(A, B, C, D, E, F, G, H);
+
+impl Ultralight {
+    /// Install `func` as `window[name]`.
+    ///
+    /// Arguments are converted with `FromJsArgs`; an `Err` returned from
+    /// `func`, or arguments that fail to convert, are thrown as a JS `Error`.
+    pub fn register_fn<A, R, F>(
+        &mut self,
+        name: impl AsRef<str>,
+        mut func: F,
+    ) -> Result<()>
+        where A: FromJsArgs,
+              R: ToJs,
+              F: FnMut(A) -> std::result::Result<R, String> + 'static
+    {
+        let view = self.view_ref()?;
+
+        let hook = move |
+            ctx: ffi::JSContextRef,
+            _function: ffi::JSObjectRef,
+            _thisObject: ffi::JSObjectRef,
+            argumentCount: usize,
+            arguments: *const ffi::JSValueRef,
+            exception: *mut ffi::JSValueRef,
+        | -> ffi::JSValueRef {
+            unsafe {
+                let arguments = if arguments.is_null() {
+                    &[]
+                } else {
+                    std::slice::from_raw_parts(arguments, argumentCount)
+                };
+
+                let args = match A::from_js_args(&JsArgs::new(ctx, arguments)) {
+                    Ok(args) => args,
+                    Err(err) => return throw_js_error(ctx, exception, &err.to_string()),
+                };
+
+                match func(args) {
+                    Ok(value) => value.to_js().to_raw(ctx),
+                    Err(message) => throw_js_error(ctx, exception, &message),
+                }
+            }
+        };
+
+        let function = helpers::create_js_function(view, hook);
+
+        unsafe {
+            helpers::set_js_object_property(view, name.as_ref(), function)
+        }
+    }
+}
@@ -1,10 +1,21 @@
 use crate::ffi;
-use crate::helpers_internal::unpack_closure_hook_cb;
-
+use crate::error::{
+    Error,
+    Result,
+};
+use crate::strings::JsString;
+use crate::helpers_internal::{
+    js_exception,
+    make_boxed_hook,
+};
+
+/// Create a callable JS object backed by `hook`.
+///
+/// The closure is boxed and owned by the returned object; it is dropped when
+/// JavaScript garbage-collects the function.
 pub fn create_js_function<T> (
-    view: crate::View,
-    name: &'static str,
-    mut hook: &mut T
+    view: &crate::View,
+    hook: T
 ) -> ffi::JSObjectRef
     where T: FnMut(
         ffi::JSContextRef,
@@ -13,112 +24,113 @@ pub fn create_js_function<T> (
         usize,
         *const ffi::JSValueRef,
         *mut ffi::JSValueRef,
-    ) -> ffi::JSValueRef
+    ) -> ffi::JSValueRef + 'static
 {
-    unsafe {
-        let (
-            hook_closure,
-            hook_function
-        ) = unpack_closure_hook_cb(&mut hook);
-
-        let classname_str = std::ffi::CString::new(name).unwrap();
-
-        let jsclassdef = ffi::JSClassDefinition {
-            version: 0,
-            attributes: 0,
-            className: classname_str.as_ptr(),
-            parentClass: std::ptr::null_mut() as ffi::JSClassRef,
-            staticValues: std::ptr::null() as *const ffi::JSStaticValue,
-            staticFunctions: std::ptr::null() as *const ffi::JSStaticFunction,
-            initialize: None,
-            hasProperty: None,
-            getProperty: None,
-            setProperty: None,
-            deleteProperty: None,
-            getPropertyNames: None,
-            callAsConstructor: None,
-            hasInstance: None,
-            convertToType: None,
-            finalize: None,
-            callAsFunction: Some(hook_function),
-            // need to implement drop!
-            //finalize: Some(|| std::mem::drop(jsclass)),
-        };
-
-        let jsclass = ffi::JSClassCreate(
-            &jsclassdef
-        );
+    let (jsgctx, ..) = getJSContextFromView(view);
 
-        let (jsgctx, ..) = getJSContextFromView(view);
-
-        ffi::JSObjectMake(
-            jsgctx,
-            jsclass,
-            hook_closure
-        )
+    unsafe {
+        make_boxed_hook(jsgctx, Box::new(hook))
     }
 }
 
 pub fn getJSContextFromView(
-    view: crate::View
+    view: &crate::View
 ) -> (ffi::JSContextRef, ffi::JSObjectRef) {
     unsafe {
-        let jsgctx = ffi::ulViewGetJSContext(view);
+        let jsgctx = ffi::ulViewGetJSContext(view.raw());
         let jsgctx_object = ffi::JSContextGetGlobalObject(jsgctx);
 
         (jsgctx, jsgctx_object)
     }
 }
 
-pub fn set_js_object_property(
-    view: crate::View,
-    name: &'static str,
+/// # Safety
+///
+/// `object` must be a live value belonging to the view's JS context.
+pub unsafe fn set_js_object_property(
+    view: &crate::View,
+    name: &str,
     object: ffi::JSObjectRef
-) {
+) -> Result<()> {
     unsafe {
         let (jsgctx, jsgctx_object) = getJSContextFromView(view);
 
-        let c_name = std::ffi::CString::new(
-            name
-        ).unwrap();
-
-        let propertyName = ffi::JSStringCreateWithUTF8CString(
-            c_name.as_ptr()
-        );
+        let propertyName = JsString::new(name);
 
         ffi::JSObjectSetProperty(
             jsgctx,
             jsgctx_object,
-            propertyName,
+            propertyName.raw(),
             object,
             0,
-            std::ptr::null_mut() as *mut *const ffi::OpaqueJSValue
+            std::ptr::null_mut()
         );
     }
+
+    Ok(())
 }
 
 // "window.styla={callbacks:[{render:global_spotfire_hook}]};"
 
 pub fn evaluate_script(
-    view: crate::View,
-    script: &'static str
-) -> ffi::JSValueRef {
+    view: &crate::View,
+    script: &str
+) -> Result<ffi::JSValueRef> {
     unsafe {
         let (jsgctx, jsgctx_object) = getJSContextFromView(view);
 
-        let script_c_str = std::ffi::CString::new(
-            script
-        ).unwrap();
+        let script = JsString::new(script);
+        let mut exception: ffi::JSValueRef = std::ptr::null();
 
-        ffi::JSEvaluateScript(
+        let value = ffi::JSEvaluateScript(
             jsgctx,
-            ffi::JSStringCreateWithUTF8CString(
-                script_c_str.as_ptr()
-            ),
+            script.raw(),
             jsgctx_object,
-            std::ptr::null_mut() as *mut ffi::OpaqueJSString,
-            ffi::kJSPropertyAttributeNone as i32,
-            std::ptr::null_mut() as *mut *const ffi::OpaqueJSValue
-        )
+            std::ptr::null_mut(),
+            1,
+            &mut exception
+        );
+
+        if exception.is_null() {
+            Ok(value)
+        } else {
+            Err(js_exception(jsgctx, exception))
+        }
+    }
+}
+
+/// Parse `script` without running it, reporting syntax errors as
+/// `Error::JsException`.
+pub fn check_script_syntax(
+    view: &crate::View,
+    script: &str
+) -> Result<()> {
+    unsafe {
+        let (jsgctx, _) = getJSContextFromView(view);
+
+        let script = JsString::new(script);
+        let mut exception: ffi::JSValueRef = std::ptr::null();
+
+        let valid = ffi::JSCheckScriptSyntax(
+            jsgctx,
+            script.raw(),
+            std::ptr::null_mut(),
+            1,
+            &mut exception
+        );
+
+        if valid {
+            Ok(())
+        } else if exception.is_null() {
+            Err(Error::JsException {
+                message: "SyntaxError".to_string(),
+                line: None,
+                column: None,
+                source_url: None,
+                stack: None,
+            })
+        } else {
+            Err(js_exception(jsgctx, exception))
+        }
     }
 }
@@ -1,139 +1,342 @@
 use crate::{
     ffi,
+    console::{
+        ConsoleMessage,
+        MessageLevel,
+        MessageSource,
+    },
+    cursor::Cursor,
+    error::Error,
+    strings::{
+        JsString,
+        UlString,
+    },
+    value::JsValue,
     View
 };
 
 use std::{
+    cell::RefCell,
+    ffi::CString,
+    mem::ManuallyDrop,
     os::raw::{
+        c_char,
+        c_uint,
         c_void
     },
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+    path::Path,
 };
 
-// All callbacks that accept take a (view: ULView) argument
+#[cfg(unix)]
+pub fn path_to_cstring(path: &Path) -> Result<CString, std::ffi::NulError> {
+    use std::os::unix::ffi::OsStrExt;
+
+    CString::new(path.as_os_str().as_bytes())
+}
 
-pub unsafe fn unpack_closure_view_cb<F>(closure: &mut F) -> (*mut c_void, unsafe extern "C" fn(*mut c_void, View))
-    where
-        F: FnMut(View),
-{
-    extern "C" fn trampoline<F>(data: *mut c_void, n: View)
-        where
-            F: FnMut(View),
-    {
-        let closure: &mut F = unsafe { &mut *(data as *mut F) };
-        (*closure)(n);
+#[cfg(not(unix))]
+pub fn path_to_cstring(path: &Path) -> Result<CString, std::ffi::NulError> {
+    CString::new(path.to_string_lossy().into_owned())
+}
+
+/// Quote `string` as a JSON string literal, which is also a valid JS one.
+pub fn quote_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len() + 2);
+
+    escaped.push('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            // valid in JSON, but line terminators in older JS engines
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
     }
 
-    (closure as *mut F as *mut c_void, trampoline::<F>)
+    escaped.push('"');
+
+    escaped
+}
+
+// `data` of every view callback points at the `RefCell` holding the boxed
+// closure, owned by the `View`.
+//
+// A closure may make the view call it again, e.g. a title callback setting
+// `document.title`; the nested call finds the cell borrowed and is dropped
+// instead of aliasing the running closure.
+unsafe fn call_view_callback<C>(data: *mut c_void, call: impl FnOnce(&mut C)) {
+    let cell = &*(data as *const RefCell<C>);
+
+    let mut callback = match cell.try_borrow_mut() {
+        Ok(callback) => callback,
+        Err(_) => return,
+    };
+
+    // unwinding into Ultralight is undefined behaviour; the panic hook has
+    // already reported the panic and there is no one else to tell
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| call(&mut callback)));
+}
+
+// All callbacks that accept take a (view: ULView) argument
+
+pub type ViewCallback = Box<dyn FnMut(&View)>;
+
+pub unsafe extern "C" fn view_cb_trampoline(data: *mut c_void, caller: ffi::ULView) {
+    // the caller still owns the view, never destroy it from here
+    let view = ManuallyDrop::new(View::from_raw(caller));
+
+    call_view_callback(data, |callback: &mut ViewCallback| callback(&view));
+}
+
+// Callbacks taking a (view: ULView, string: ULString) pair: title, URL and tooltip
+
+pub type ViewStringCallback = Box<dyn FnMut(&View, String)>;
+
+pub unsafe extern "C" fn view_string_cb_trampoline(data: *mut c_void, caller: ffi::ULView, string: ffi::ULString) {
+    let view = ManuallyDrop::new(View::from_raw(caller));
+    let string = UlString::borrowed(string).to_string_lossy();
+
+    call_view_callback(data, |callback: &mut ViewStringCallback| callback(&view, string));
+}
+
+pub type ViewCursorCallback = Box<dyn FnMut(&View, Cursor)>;
+
+pub unsafe extern "C" fn view_cursor_cb_trampoline(data: *mut c_void, caller: ffi::ULView, cursor: ffi::ULCursor) {
+    let view = ManuallyDrop::new(View::from_raw(caller));
+
+    // newer SDKs may add cursors we don't know about yet
+    let cursor = Cursor::from_raw(cursor).unwrap_or(Cursor::Pointer);
+
+    call_view_callback(data, |callback: &mut ViewCursorCallback| callback(&view, cursor));
+}
+
+pub type ViewConsoleCallback = Box<dyn FnMut(&View, &ConsoleMessage)>;
+
+pub unsafe extern "C" fn view_console_cb_trampoline(
+    data: *mut c_void,
+    caller: ffi::ULView,
+    source: ffi::ULMessageSource,
+    level: ffi::ULMessageLevel,
+    message: ffi::ULString,
+    line_number: c_uint,
+    column_number: c_uint,
+    source_id: ffi::ULString,
+) {
+    let view = ManuallyDrop::new(View::from_raw(caller));
+
+    let message = ConsoleMessage {
+        level: MessageLevel::from_raw(level).unwrap_or(MessageLevel::Log),
+        source: MessageSource::from_raw(source).unwrap_or(MessageSource::Other),
+        message: UlString::borrowed(message).to_string_lossy(),
+        line: line_number,
+        column: column_number,
+        source_id: UlString::borrowed(source_id).to_string_lossy(),
+    };
+
+    call_view_callback(data, |callback: &mut ViewConsoleCallback| callback(&view, &message));
+}
+
+/// Turn a thrown JS value into `Error::JsException`, picking up the
+/// `line`, `column`, `sourceURL` and `stack` properties JavaScriptCore
+/// attaches to `Error` objects.
+pub unsafe fn js_exception(ctx: ffi::JSContextRef, exception: ffi::JSValueRef) -> Error {
+    let message = JsString::from_raw(
+        ffi::JSValueToStringCopy(ctx, exception, std::ptr::null_mut())
+    );
+
+    let error_property = |name: &str| -> JsValue {
+        if !ffi::JSValueIsObject(ctx, exception) {
+            return JsValue::Undefined;
+        }
+
+        let object = ffi::JSValueToObject(ctx, exception, std::ptr::null_mut());
+        let name = JsString::new(name);
+
+        let property = ffi::JSObjectGetProperty(ctx, object, name.raw(), std::ptr::null_mut());
+
+        JsValue::from_raw(ctx, property).unwrap_or(JsValue::Undefined)
+    };
+
+    let number = |value: JsValue| match value {
+        JsValue::Number(number) => Some(number as u32),
+        _ => None,
+    };
+
+    let string = |value: JsValue| match value {
+        JsValue::String(string) if !string.is_empty() => Some(string),
+        _ => None,
+    };
+
+    Error::JsException {
+        message: message.to_string_lossy(),
+        line: number(error_property("line")),
+        column: number(error_property("column")),
+        source_url: string(error_property("sourceURL")),
+        stack: string(error_property("stack")),
+    }
 }
 
 // JSContextHooks
-type ClosureHookCallbackSig = unsafe extern "C" fn(
+
+// Hooks owned by their JS object: the box lives in the object's private
+// data and is dropped by the class finalizer once JS collects the object.
+pub type BoxedHook = Box<dyn FnMut(
     ffi::JSContextRef,
     ffi::JSObjectRef,
     ffi::JSObjectRef,
     usize,
     *const ffi::JSValueRef,
-    *mut ffi::JSValueRef
-) -> ffi::JSValueRef;
-
-pub unsafe fn unpack_closure_hook_cb<F>(closure: &mut F) -> (*mut c_void, ClosureHookCallbackSig)
-    where
-        F: FnMut(
-            ffi::JSContextRef,
-            ffi::JSObjectRef,
-            ffi::JSObjectRef,
-            usize,
-            *const ffi::JSValueRef,
-            *mut ffi::JSValueRef,
-        ) -> ffi::JSValueRef,
-{
-    unsafe extern "C" fn trampoline<F>(
-        ctx: ffi::JSContextRef,
-        function: ffi::JSObjectRef,
-        thisObject: ffi::JSObjectRef,
-        argumentCount: usize,
-        arguments: *const ffi::JSValueRef,
-        exception: *mut ffi::JSValueRef,
-    ) -> ffi::JSValueRef
-        where
-            F: FnMut(
-                ffi::JSContextRef,
-                ffi::JSObjectRef,
-                ffi::JSObjectRef,
-                usize,
-                *const ffi::JSValueRef,
-                *mut ffi::JSValueRef,
-            ) -> ffi::JSValueRef,
-    {
-        let closure: &mut F = &mut *(ffi::JSObjectGetPrivate(function) as *mut F);
-
-        (*closure)(
+    *mut ffi::JSValueRef,
+) -> ffi::JSValueRef>;
+
+unsafe extern "C" fn call_boxed_hook(
+    ctx: ffi::JSContextRef,
+    function: ffi::JSObjectRef,
+    thisObject: ffi::JSObjectRef,
+    argumentCount: usize,
+    arguments: *const ffi::JSValueRef,
+    exception: *mut ffi::JSValueRef,
+) -> ffi::JSValueRef {
+    let hook = ffi::JSObjectGetPrivate(function) as *mut BoxedHook;
+
+    if hook.is_null() {
+        return ffi::JSValueMakeUndefined(ctx);
+    }
+
+    // unwinding into JavaScriptCore is undefined behaviour
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        (*hook)(ctx, function, thisObject, argumentCount, arguments, exception)
+    }));
+
+    match result {
+        Ok(value) => value,
+        Err(_) => throw_js_error(ctx, exception, "rust callback panicked"),
+    }
+}
+
+unsafe extern "C" fn finalize_boxed_hook(object: ffi::JSObjectRef) {
+    let hook = ffi::JSObjectGetPrivate(object) as *mut BoxedHook;
+
+    if !hook.is_null() {
+        ffi::JSObjectSetPrivate(object, std::ptr::null_mut());
+
+        drop(Box::from_raw(hook));
+    }
+}
+
+// One class serves every boxed hook; it is created on first use and
+// released when the thread that created it exits.
+struct HookClass(ffi::JSClassRef);
+
+impl HookClass {
+    fn new() -> HookClass {
+        let jsclassdef = ffi::JSClassDefinition {
+            version: 0,
+            attributes: 0,
+            className: b"RustFunction\0".as_ptr() as *const c_char,
+            parentClass: std::ptr::null_mut(),
+            staticValues: std::ptr::null(),
+            staticFunctions: std::ptr::null(),
+            initialize: None,
+            hasProperty: None,
+            getProperty: None,
+            setProperty: None,
+            deleteProperty: None,
+            getPropertyNames: None,
+            callAsFunction: Some(call_boxed_hook),
+            callAsConstructor: None,
+            hasInstance: None,
+            convertToType: None,
+            finalize: Some(finalize_boxed_hook),
+        };
+
+        HookClass(unsafe {
+            ffi::JSClassCreate(&jsclassdef)
+        })
+    }
+}
+
+impl Drop for HookClass {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::JSClassRelease(self.0);
+        }
+    }
+}
+
+thread_local! {
+    static HOOK_CLASS: HookClass = HookClass::new();
+}
+
+/// Create a callable JS object that owns `hook`.
+pub unsafe fn make_boxed_hook(ctx: ffi::JSContextRef, hook: BoxedHook) -> ffi::JSObjectRef {
+    HOOK_CLASS.with(|jsclass| {
+        ffi::JSObjectMake(
             ctx,
-            function,
-            thisObject,
-            argumentCount,
-            arguments,
-            exception
+            jsclass.0,
+            Box::into_raw(Box::new(hook)) as *mut c_void
         )
+    })
+}
+
+/// Store a new `Error(message)` in `exception` and return `undefined`.
+pub unsafe fn throw_js_error(
+    ctx: ffi::JSContextRef,
+    exception: *mut ffi::JSValueRef,
+    message: &str
+) -> ffi::JSValueRef {
+    if !exception.is_null() {
+        let message = JsString::new(message);
+        let message = ffi::JSValueMakeString(ctx, message.raw());
+
+        *exception = ffi::JSObjectMakeError(ctx, 1, &message, std::ptr::null_mut());
     }
 
-    (closure as *mut F as *mut c_void, trampoline::<F>)
+    ffi::JSValueMakeUndefined(ctx)
 }
 
-static msg_parsing_failed: &'static str = "!parsing failed!";
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub unsafe extern "C" fn log_forward_cb(
-    user_data: *mut ::std::os::raw::c_void,
-    caller: View,
-    source: ffi::ULMessageSource,           /* u32 */
-    level: ffi::ULMessageLevel,             /* u32 */
-    message: ffi::ULString,                 /* *mut C_String aka *mut u8 */
-    line_number: ::std::os::raw::c_uint,    /* u32 */
-    column_number: ::std::os::raw::c_uint,  /* u32 */
-    source_id: ffi::ULString,               /* *mut C_String aka *mut u8 */
-) {
-    let level = match level {
-        ffi::ULMessageLevel_kMessageLevel_Log => "log",
-        ffi::ULMessageLevel_kMessageLevel_Warning => "warning",
-        ffi::ULMessageLevel_kMessageLevel_Error => "error",
-        ffi::ULMessageLevel_kMessageLevel_Debug => "debug",
-        ffi::ULMessageLevel_kMessageLevel_Info => "info",
-        _ => "unknown",
-    };
+    #[test]
+    fn quotes_plain_strings() {
+        assert_eq!(quote_string(""), r#""""#);
+        assert_eq!(quote_string("#main > a"), "\"#main > a\"");
+    }
 
-    let source = match source {
-        ffi::ULMessageSource_kMessageSource_XML => "xml",
-        ffi::ULMessageSource_kMessageSource_JS => "js",
-        ffi::ULMessageSource_kMessageSource_Network => "network",
-        ffi::ULMessageSource_kMessageSource_ConsoleAPI => "consoleapi",
-        ffi::ULMessageSource_kMessageSource_Storage => "storage",
-        ffi::ULMessageSource_kMessageSource_AppCache => "appcache",
-        ffi::ULMessageSource_kMessageSource_Rendering => "rendering",
-        ffi::ULMessageSource_kMessageSource_CSS => "css",
-        ffi::ULMessageSource_kMessageSource_Security => "security",
-        ffi::ULMessageSource_kMessageSource_ContentBlocker => "contentblocker",
-        ffi::ULMessageSource_kMessageSource_Other => "other",
-        _ => "unknown",
-    };
+    #[test]
+    fn escapes_quotes_and_backslashes() {
+        assert_eq!(quote_string(r#"a[href="x"]"#), r#""a[href=\"x\"]""#);
+        assert_eq!(quote_string(r"C:\path"), r#""C:\\path""#);
+        assert_eq!(quote_string(r#"\""#), r#""\\\"""#);
+    }
 
-    let message = match String::from_utf16(std::slice::from_raw_parts_mut(
-        ffi::ulStringGetData(message),
-        ffi::ulStringGetLength(message),
-    )) {
-        Ok(msg) => msg,
-        Err(_) => msg_parsing_failed.to_string(),
-    };
+    #[test]
+    fn escapes_control_characters() {
+        assert_eq!(quote_string("a\nb\rc\td"), r#""a\nb\rc\td""#);
+        assert_eq!(quote_string("\u{0}\u{8}\u{1f}"), r#""\u0000\u0008\u001f""#);
+    }
 
-    let source_id = match String::from_utf16(std::slice::from_raw_parts_mut(
-        ffi::ulStringGetData(source_id),
-        ffi::ulStringGetLength(source_id),
-    )) {
-        Ok(src) => src,
-        Err(_) => msg_parsing_failed.to_string(),
-    };
+    #[test]
+    fn escapes_js_line_terminators() {
+        assert_eq!(quote_string("a\u{2028}b\u{2029}c"), r#""a\u2028b\u2029c""#);
+    }
 
-    println!(
-        "[{}] [{}] {} ({}:{}:{})",
-        level, source, message, source_id, line_number, column_number
-    );
+    #[test]
+    fn keeps_non_ascii_text() {
+        assert_eq!(quote_string("Привет, 世界 🎉"), "\"Привет, 世界 🎉\"");
+        assert_eq!(quote_string("\u{7f}é"), "\"\u{7f}é\"");
+    }
 }
@@ -0,0 +1,445 @@
+use crate::{
+    ffi,
+    strings::UlString,
+    Result,
+    Ultralight,
+    View,
+};
+
+use std::ops::{
+    BitOr,
+    BitOrAssign,
+};
+
+/// Mouse button, mapped to `ULMouseButton`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+}
+
+impl MouseButton {
+    pub(crate) fn to_raw(self) -> ffi::ULMouseButton {
+        match self {
+            MouseButton::Left => ffi::ULMouseButton_kMouseButton_Left,
+            MouseButton::Middle => ffi::ULMouseButton_kMouseButton_Middle,
+            MouseButton::Right => ffi::ULMouseButton_kMouseButton_Right,
+        }
+    }
+}
+
+/// Keyboard modifier flags, as passed to `ulCreateKeyEvent`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers(u32);
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers(0);
+    pub const ALT: Modifiers = Modifiers(1 << 0);
+    pub const CTRL: Modifiers = Modifiers(1 << 1);
+    pub const META: Modifiers = Modifiers(1 << 2);
+    pub const SHIFT: Modifiers = Modifiers(1 << 3);
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, other: Modifiers) -> Modifiers {
+        Modifiers(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, other: Modifiers) {
+        self.0 |= other.0;
+    }
+}
+
+/// Keyboard key, mapped to its Windows virtual key code (which is what
+/// Ultralight expects on every platform).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    Backspace,
+    Tab,
+    Enter,
+    Shift,
+    Control,
+    Alt,
+    Pause,
+    CapsLock,
+    Escape,
+    Space,
+    PageUp,
+    PageDown,
+    End,
+    Home,
+    Left,
+    Up,
+    Right,
+    Down,
+    Insert,
+    Delete,
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Meta,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+const LETTERS: [Key; 26] = [
+    Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I,
+    Key::J, Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q, Key::R,
+    Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+];
+
+const DIGITS: [Key; 10] = [
+    Key::Digit0, Key::Digit1, Key::Digit2, Key::Digit3, Key::Digit4,
+    Key::Digit5, Key::Digit6, Key::Digit7, Key::Digit8, Key::Digit9,
+];
+
+impl Key {
+    pub fn virtual_key_code(self) -> i32 {
+        match self {
+            Key::Backspace => 0x08,
+            Key::Tab => 0x09,
+            Key::Enter => 0x0D,
+            Key::Shift => 0x10,
+            Key::Control => 0x11,
+            Key::Alt => 0x12,
+            Key::Pause => 0x13,
+            Key::CapsLock => 0x14,
+            Key::Escape => 0x1B,
+            Key::Space => 0x20,
+            Key::PageUp => 0x21,
+            Key::PageDown => 0x22,
+            Key::End => 0x23,
+            Key::Home => 0x24,
+            Key::Left => 0x25,
+            Key::Up => 0x26,
+            Key::Right => 0x27,
+            Key::Down => 0x28,
+            Key::Insert => 0x2D,
+            Key::Delete => 0x2E,
+            Key::Digit0 => 0x30,
+            Key::Digit1 => 0x31,
+            Key::Digit2 => 0x32,
+            Key::Digit3 => 0x33,
+            Key::Digit4 => 0x34,
+            Key::Digit5 => 0x35,
+            Key::Digit6 => 0x36,
+            Key::Digit7 => 0x37,
+            Key::Digit8 => 0x38,
+            Key::Digit9 => 0x39,
+            Key::A => 0x41,
+            Key::B => 0x42,
+            Key::C => 0x43,
+            Key::D => 0x44,
+            Key::E => 0x45,
+            Key::F => 0x46,
+            Key::G => 0x47,
+            Key::H => 0x48,
+            Key::I => 0x49,
+            Key::J => 0x4A,
+            Key::K => 0x4B,
+            Key::L => 0x4C,
+            Key::M => 0x4D,
+            Key::N => 0x4E,
+            Key::O => 0x4F,
+            Key::P => 0x50,
+            Key::Q => 0x51,
+            Key::R => 0x52,
+            Key::S => 0x53,
+            Key::T => 0x54,
+            Key::U => 0x55,
+            Key::V => 0x56,
+            Key::W => 0x57,
+            Key::X => 0x58,
+            Key::Y => 0x59,
+            Key::Z => 0x5A,
+            Key::Meta => 0x5B,
+            Key::F1 => 0x70,
+            Key::F2 => 0x71,
+            Key::F3 => 0x72,
+            Key::F4 => 0x73,
+            Key::F5 => 0x74,
+            Key::F6 => 0x75,
+            Key::F7 => 0x76,
+            Key::F8 => 0x77,
+            Key::F9 => 0x78,
+            Key::F10 => 0x79,
+            Key::F11 => 0x7A,
+            Key::F12 => 0x7B,
+        }
+    }
+
+    /// The key that types `c` on a US layout, if there is a dedicated one.
+    pub fn from_char(c: char) -> Option<Key> {
+        match c {
+            ' ' => Some(Key::Space),
+            '\t' => Some(Key::Tab),
+            '\r' | '\n' => Some(Key::Enter),
+            '0'..='9' => Some(DIGITS[c as usize - '0' as usize]),
+            'a'..='z' => Some(LETTERS[c as usize - 'a' as usize]),
+            'A'..='Z' => Some(LETTERS[c as usize - 'A' as usize]),
+            _ => None,
+        }
+    }
+
+    /// Text the key inserts, if any.
+    fn text(self, modifiers: Modifiers) -> Option<String> {
+        let shift = modifiers.contains(Modifiers::SHIFT);
+
+        match self {
+            Key::Space => Some(" ".to_string()),
+            Key::Tab => Some("\t".to_string()),
+            Key::Enter => Some("\r".to_string()),
+            key => {
+                let code = key.virtual_key_code() as u8;
+
+                match code {
+                    0x30..=0x39 => Some((code as char).to_string()),
+                    0x41..=0x5A if shift => Some((code as char).to_string()),
+                    0x41..=0x5A => Some((code as char).to_ascii_lowercase().to_string()),
+                    _ => None,
+                }
+            },
+        }
+    }
+}
+
+impl<'r> View<'r> {
+    fn fire_mouse_event(&self, kind: ffi::ULMouseEventType, x: i32, y: i32, button: ffi::ULMouseButton) {
+        unsafe {
+            let event = ffi::ulCreateMouseEvent(kind, x, y, button);
+
+            ffi::ulViewFireMouseEvent(self.raw(), event);
+
+            ffi::ulDestroyMouseEvent(event);
+        }
+    }
+
+    /// Move the mouse to (`x`, `y`) in view coordinates.
+    pub fn mouse_move(&self, x: i32, y: i32) {
+        self.fire_mouse_event(
+            ffi::ULMouseEventType_kMouseEventType_MouseMoved,
+            x, y,
+            ffi::ULMouseButton_kMouseButton_None
+        );
+    }
+
+    /// Press `button` at (`x`, `y`).
+    pub fn mouse_down(&self, x: i32, y: i32, button: MouseButton) {
+        self.fire_mouse_event(ffi::ULMouseEventType_kMouseEventType_MouseDown, x, y, button.to_raw());
+    }
+
+    /// Release `button` at (`x`, `y`).
+    pub fn mouse_up(&self, x: i32, y: i32, button: MouseButton) {
+        self.fire_mouse_event(ffi::ULMouseEventType_kMouseEventType_MouseUp, x, y, button.to_raw());
+    }
+
+    fn fire_key_event(&self, kind: ffi::ULKeyEventType, modifiers: Modifiers, virtual_key_code: i32, text: &str) {
+        let text = UlString::new(text);
+
+        unsafe {
+            let event = ffi::ulCreateKeyEvent(
+                kind,
+                modifiers.bits(),
+                virtual_key_code,
+                0,
+                text.raw(),
+                text.raw(),
+                false,
+                false,
+                false
+            );
+
+            ffi::ulViewFireKeyEvent(self.raw(), event);
+
+            ffi::ulDestroyKeyEvent(event);
+        }
+    }
+
+    /// Send the (raw) key down event for `key`; does not insert text.
+    pub fn key_down(&self, key: Key, modifiers: Modifiers) {
+        self.fire_key_event(ffi::ULKeyEventType_kKeyEventType_RawKeyDown, modifiers, key.virtual_key_code(), "");
+    }
+
+    /// Send the key up event for `key`.
+    pub fn key_up(&self, key: Key, modifiers: Modifiers) {
+        self.fire_key_event(ffi::ULKeyEventType_kKeyEventType_KeyUp, modifiers, key.virtual_key_code(), "");
+    }
+
+    /// Insert `text` into the focused element, as typed characters do.
+    pub fn insert_text(&self, text: &str) {
+        self.fire_key_event(ffi::ULKeyEventType_kKeyEventType_Char, Modifiers::NONE, 0, text);
+    }
+}
+
+impl Ultralight {
+    /// Press and release `key` with `modifiers` held.
+    ///
+    /// Keys that produce text insert it unless `CTRL`, `ALT` or `META` is
+    /// held, so `key_combo(Modifiers::CTRL, Key::A)` selects all instead of
+    /// typing an "a".
+    pub fn key_combo(&mut self, modifiers: Modifiers, key: Key) -> Result<()> {
+        let view = self.view_ref()?;
+
+        view.key_down(key, modifiers);
+
+        if !(modifiers.contains(Modifiers::CTRL) || modifiers.contains(Modifiers::ALT) || modifiers.contains(Modifiers::META)) {
+            if let Some(text) = key.text(modifiers) {
+                view.fire_key_event(ffi::ULKeyEventType_kKeyEventType_Char, modifiers, key.virtual_key_code(), &text);
+            }
+        }
+
+        view.key_up(key, modifiers);
+
+        Ok(())
+    }
+
+    /// Press and release `key`.
+    pub fn press(&mut self, key: Key) -> Result<()> {
+        self.key_combo(Modifiers::NONE, key)
+    }
+
+    /// Type `text` into the focused element character by character.
+    ///
+    /// Characters with a dedicated key also get key down and up events;
+    /// everything else is only inserted as text.
+    pub fn type_text(&mut self, text: impl AsRef<str>) -> Result<()> {
+        let view = self.view_ref()?;
+
+        let mut buffer = [0; 4];
+
+        for c in text.as_ref().chars() {
+            let key = Key::from_char(c);
+            let modifiers = if c.is_ascii_uppercase() { Modifiers::SHIFT } else { Modifiers::NONE };
+
+            if let Some(key) = key {
+                view.key_down(key, modifiers);
+            }
+
+            let text = match c {
+                '\n' => "\r",
+                c => c.encode_utf8(&mut buffer),
+            };
+
+            view.fire_key_event(
+                ffi::ULKeyEventType_kKeyEventType_Char,
+                modifiers,
+                key.map_or(0, Key::virtual_key_code),
+                text
+            );
+
+            if let Some(key) = key {
+                view.key_up(key, modifiers);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Move the mouse to (`x`, `y`), triggering hover effects.
+    pub fn mouse_move(&mut self, x: i32, y: i32) -> Result<()> {
+        self.view_ref()?.mouse_move(x, y);
+
+        Ok(())
+    }
+
+    /// Move to (`x`, `y`), then press and release `button` there.
+    pub fn click(&mut self, x: i32, y: i32, button: MouseButton) -> Result<()> {
+        let view = self.view_ref()?;
+
+        view.mouse_move(x, y);
+        view.mouse_down(x, y, button);
+        view.mouse_up(x, y, button);
+
+        Ok(())
+    }
+
+    /// Two left clicks in quick succession at (`x`, `y`).
+    pub fn double_click(&mut self, x: i32, y: i32) -> Result<()> {
+        self.click(x, y, MouseButton::Left)?;
+        self.click(x, y, MouseButton::Left)
+    }
+
+    /// Press the left button at `from`, move to `to` in `steps` even steps
+    /// and release it there. The renderer is updated after every step so
+    /// the page sees the intermediate positions.
+    pub fn drag(&mut self, from: (i32, i32), to: (i32, i32), steps: u32) -> Result<()> {
+        let steps = steps.max(1);
+
+        self.mouse_move(from.0, from.1)?;
+        self.view_ref()?.mouse_down(from.0, from.1, MouseButton::Left);
+
+        for step in 1..=steps {
+            let t = step as f64 / steps as f64;
+
+            let x = from.0 + ((to.0 - from.0) as f64 * t).round() as i32;
+            let y = from.1 + ((to.1 - from.1) as f64 * t).round() as i32;
+
+            self.mouse_move(x, y)?;
+            self.update();
+        }
+
+        self.view_ref()?.mouse_up(to.0, to.1, MouseButton::Left);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    ffi,
+    helpers,
+    helpers_internal::js_exception,
+    Error,
+    Result,
+    strings::JsString,
+    Ultralight,
+};
+
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+impl Ultralight {
+    /// Evaluate `script`, serialize its result with `JSON.stringify` semantics
+    /// and deserialize that into `T`. `undefined` is treated as `null`;
+    /// exceptions thrown while serializing are returned as `Error::JsException`.
+    pub fn evaluate_json<T: DeserializeOwned>(
+        &mut self,
+        script: impl AsRef<str>,
+    ) -> Result<T> {
+        let (jsgctx, _) = helpers::getJSContextFromView(self.view_ref()?);
+        let value = self.evaluate_script(script)?;
+
+        let json = unsafe {
+            if ffi::JSValueIsUndefined(jsgctx, value) {
+                "null".to_string()
+            } else {
+                let mut exception: ffi::JSValueRef = std::ptr::null();
+
+                let json = ffi::JSValueCreateJSONString(jsgctx, value, 0, &mut exception);
+
+                if !exception.is_null() {
+                    return Err(js_exception(jsgctx, exception));
+                }
+
+                if json.is_null() {
+                    // JSON.stringify yields undefined for functions and symbols
+                    return Err(Error::Conversion("value has no JSON representation".to_string()));
+                }
+
+                JsString::from_raw(json).to_string_lossy()
+            }
+        };
+
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Serialize `value` to JSON and assign the parsed result to
+    /// `window[name]`.
+    pub fn set_global<T: Serialize + ?Sized>(
+        &mut self,
+        name: impl AsRef<str>,
+        value: &T,
+    ) -> Result<()> {
+        let json = JsString::new(&serde_json::to_string(value)?);
+
+        unsafe {
+            let (jsgctx, jsgctx_object) = helpers::getJSContextFromView(self.view_ref()?);
+
+            let value = ffi::JSValueMakeFromJSONString(jsgctx, json.raw());
+
+            if value.is_null() {
+                return Err(Error::Conversion(format!("javascript rejected the JSON for {}", name.as_ref())));
+            }
+
+            let name = JsString::new(name.as_ref());
+
+            ffi::JSObjectSetProperty(
+                jsgctx,
+                jsgctx_object,
+                name.raw(),
+                value,
+                0,
+                std::ptr::null_mut()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,722 @@
+#![allow(
+    non_camel_case_types,
+    non_upper_case_globals,
+    non_snake_case,
+    dead_code,
+    unused_variables,
+    unused_must_use
+)]
+
+#[cfg(not(feature = "bindgen"))]
+#[path = "ffi.rs"]
+mod bindings;
+
+#[cfg(feature = "bindgen")]
+mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/ffi.rs"));
+}
+
+/// Handle types and constants from the Ultralight C API that appear in the
+/// public interface. The extern functions stay crate-private.
+pub mod ffi {
+    pub(crate) use crate::bindings::*;
+
+    pub use crate::bindings::{
+        JSContextRef,
+        JSObjectRef,
+        JSStringRef,
+        JSValueRef,
+        JSType,
+        JSType_kJSTypeUndefined,
+        JSType_kJSTypeNull,
+        JSType_kJSTypeBoolean,
+        JSType_kJSTypeNumber,
+        JSType_kJSTypeString,
+        JSType_kJSTypeObject,
+        OpaqueJSContext,
+        OpaqueJSString,
+        OpaqueJSValue,
+        ULMessageLevel,
+        ULMessageSource,
+        ULCursor,
+        ULMouseButton,
+        ULBitmapFormat,
+    };
+}
+
+pub mod error;
+pub mod helpers;
+pub mod config;
+pub mod renderer;
+pub mod view;
+pub mod bitmap;
+pub mod cursor;
+pub mod strings;
+pub mod value;
+pub mod function;
+pub mod console;
+pub mod wait;
+pub mod input;
+mod actions;
+mod navigation;
+
+#[cfg(feature = "serde")]
+mod json;
+
+use helpers::{
+    check_script_syntax,
+    evaluate_script,
+    set_js_object_property,
+    create_js_function,
+};
+
+use std::{
+    cell::RefCell,
+    path::Path,
+    rc::Rc,
+};
+
+mod helpers_internal;
+use console::ConsoleState;
+use helpers_internal::{
+    path_to_cstring,
+    ViewCallback,
+    ViewStringCallback,
+};
+use navigation::LoadCounter;
+
+pub use renderer::Renderer;
+pub use view::View;
+pub use bitmap::Bitmap;
+pub use cursor::Cursor;
+pub use console::{
+    ConsoleMessage,
+    ErrorPolicy,
+    MessageLevel,
+    MessageSource,
+};
+pub use strings::{
+    JsString,
+    UlString,
+};
+pub use value::{
+    FromJs,
+    JsValue,
+    ToJs,
+    TypedArrayKind,
+};
+pub use function::{
+    FromJsArgs,
+    JsArgs,
+};
+pub use input::{
+    Key,
+    Modifiers,
+    MouseButton,
+};
+pub use wait::{
+    CancellationToken,
+    Condition,
+};
+pub use error::{
+    Error,
+    Result,
+};
+
+pub type Config = config::UltralightConfig;
+
+// Expose a `View` callback setter and its matching remover on `Ultralight`
+macro_rules! forward_view_callback (
+    ($set:ident, $clear:ident, $bound:path) => (
+        /// See the method of the same name on `View`.
+        pub fn $set<T>(&mut self, cb: T) -> Result<()>
+            where T: $bound + 'static
+        {
+            self.view_mut()?.$set(cb);
+
+            Ok(())
+        }
+
+        pub fn $clear(&mut self) -> Result<()> {
+            self.view_mut()?.$clear();
+
+            Ok(())
+        }
+    )
+);
+
+pub struct Ultralight {
+    // declared before `renderer` so it is dropped first
+    view: Option<View<'static>>,
+    renderer: Renderer,
+    config: Config,
+    console: Rc<RefCell<ConsoleState>>,
+    cancellation: Option<CancellationToken>,
+    url_chain: Rc<RefCell<Vec<String>>>,
+    loads: Rc<LoadCounter>,
+}
+
+impl Ultralight {
+    pub fn new(config: Option<Config>, renderer: Option<Renderer>) -> Ultralight {
+        let ulconfig = match config {
+            Some(config) => config,
+            None => Config::new()
+        };
+
+        let used_renderer = match renderer {
+            Some(renderer) => renderer,
+            None => Renderer::new(&ulconfig)
+        };
+
+        Ultralight {
+            view: None,
+            renderer: used_renderer,
+            config: ulconfig,
+            console: Rc::new(RefCell::new(ConsoleState::default())),
+            cancellation: None,
+            url_chain: Rc::new(RefCell::new(Vec::new())),
+            loads: Rc::new(LoadCounter::default()),
+        }
+    }
+
+    pub fn view(&mut self, width: u32, height: u32, transparent: bool) {
+        // drop the previous view before creating its replacement
+        self.view = None;
+
+        // The view lives next to `self.renderer` and is dropped before it
+        // (see field order), so widening its borrow to 'static is sound.
+        let mut view = unsafe {
+            View::from_raw(
+                ffi::ulCreateView(self.renderer.raw(), width, height, transparent)
+            )
+        };
+
+        Ultralight::attach_console(&mut view, &self.console);
+        Ultralight::attach_url_chain(&mut view, &self.url_chain, None);
+        Ultralight::attach_begin_loading(&mut view, &self.loads, None);
+        Ultralight::attach_finish_loading(&mut view, &self.loads, None);
+
+        self.view = Some(view);
+    }
+
+    // Count loads starting in the main frame, then pass them on to `callback`.
+    fn attach_begin_loading(view: &mut View, loads: &Rc<LoadCounter>, mut callback: Option<ViewCallback>) {
+        let loads = loads.clone();
+
+        view.set_begin_loading_callback(move |view: &View| {
+            loads.begun.set(loads.begun.get() + 1);
+
+            if let Some(ref mut callback) = callback {
+                callback(view);
+            }
+        });
+    }
+
+    // Count loads finishing in the main frame, then pass them on to `callback`.
+    fn attach_finish_loading(view: &mut View, loads: &Rc<LoadCounter>, mut callback: Option<ViewCallback>) {
+        let loads = loads.clone();
+
+        view.set_finish_loading_callback(move |view: &View| {
+            loads.finished.set(loads.finished.get() + 1);
+
+            if let Some(ref mut callback) = callback {
+                callback(view);
+            }
+        });
+    }
+
+    // Record main frame URL changes, then pass them on to `callback`.
+    fn attach_url_chain(view: &mut View, url_chain: &Rc<RefCell<Vec<String>>>, mut callback: Option<ViewStringCallback>) {
+        let url_chain = url_chain.clone();
+
+        view.set_change_url_callback(move |view: &View, url: String| {
+            url_chain.borrow_mut().push(url.clone());
+
+            if let Some(ref mut callback) = callback {
+                callback(view, url);
+            }
+        });
+    }
+
+    fn attach_console(view: &mut View, console: &Rc<RefCell<ConsoleState>>) {
+        let console = console.clone();
+
+        view.set_console_message_callback(move |view: &View, message: &ConsoleMessage| {
+            ConsoleState::dispatch(&console, view, message);
+        });
+    }
+
+    fn view_ref(&self) -> Result<&View<'static>> {
+        self.view.as_ref().ok_or(Error::NoViewCreated)
+    }
+
+    fn view_mut(&mut self) -> Result<&mut View<'static>> {
+        self.view.as_mut().ok_or(Error::NoViewCreated)
+    }
+
+    fn raw_view(&self) -> Result<ffi::ULView> {
+        Ok(self.view_ref()?.raw())
+    }
+
+    pub fn load_url(&mut self, url: impl AsRef<str>) -> Result<()> {
+        let view = self.raw_view()?;
+
+        let url = UlString::new(url.as_ref());
+
+        self.begin_load();
+
+        unsafe {
+            ffi::ulViewLoadURL(view, url.raw());
+        }
+
+        Ok(())
+    }
+
+    pub fn load_html(&mut self, code: impl AsRef<str>) -> Result<()> {
+        let view = self.raw_view()?;
+
+        let code = UlString::new(code.as_ref());
+
+        self.begin_load();
+
+        unsafe {
+            ffi::ulViewLoadHTML(view, code.raw());
+        }
+
+        Ok(())
+    }
+
+    // Reset the per-load state before starting a navigation
+    pub(crate) fn begin_load(&mut self) {
+        self.console.borrow_mut().errors.clear();
+        self.url_chain.borrow_mut().clear();
+    }
+
+    pub fn update(&mut self) {
+        self.renderer.update();
+    }
+
+    /// Run the renderer until the main frame has loaded, without a time
+    /// limit; see `wait_until_loaded` for a bounded wait.
+    ///
+    /// With a console error policy set, fails with `Error::ConsoleErrors`
+    /// if the page logged errors the policy does not allow.
+    pub fn update_until_loaded(&mut self) -> Result<()> {
+        self.wait_loaded(None)
+    }
+
+    /// Record console errors according to `policy` from now on, or stop
+    /// recording them with `None`.
+    pub fn set_console_error_policy(&mut self, policy: Option<ErrorPolicy>) {
+        let mut console = self.console.borrow_mut();
+
+        console.policy = policy;
+        console.errors.clear();
+    }
+
+    /// Fail with the console errors recorded since the last load, if any.
+    pub fn check_console_errors(&mut self) -> Result<()> {
+        let errors = std::mem::take(&mut self.console.borrow_mut().errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ConsoleErrors(errors))
+        }
+    }
+
+    pub fn render(&mut self) {
+        self.renderer.render();
+    }
+
+    pub fn scroll(&mut self, delta_x: i32, delta_y: i32) -> Result<()> {
+        let view = self.raw_view()?;
+
+        unsafe {
+            let scrollEvent = ffi::ulCreateScrollEvent(
+                ffi::ULScrollEventType_kScrollEventType_ScrollByPixel,
+                delta_x,
+                delta_y
+            );
+
+            ffi::ulViewFireScrollEvent(view, scrollEvent);
+
+            ffi::ulDestroyScrollEvent(scrollEvent);
+
+            Ok(())
+        }
+    }
+
+    pub fn get_scroll_height(&mut self) -> Result<f64> {
+        self.evaluate("document.documentElement.scrollHeight")
+    }
+
+    forward_view_callback!(set_change_title_callback, clear_change_title_callback, FnMut(&View, String));
+
+    /// See the method of the same name on `View`.
+    ///
+    /// The URLs are recorded for `redirect_chain` before `cb` sees them.
+    pub fn set_change_url_callback<T>(&mut self, cb: T) -> Result<()>
+        where T: FnMut(&View, String) + 'static
+    {
+        let url_chain = self.url_chain.clone();
+
+        Ultralight::attach_url_chain(self.view_mut()?, &url_chain, Some(Box::new(cb)));
+
+        Ok(())
+    }
+
+    pub fn clear_change_url_callback(&mut self) -> Result<()> {
+        let url_chain = self.url_chain.clone();
+
+        Ultralight::attach_url_chain(self.view_mut()?, &url_chain, None);
+
+        Ok(())
+    }
+
+    forward_view_callback!(set_change_tooltip_callback, clear_change_tooltip_callback, FnMut(&View, String));
+    forward_view_callback!(set_change_cursor_callback, clear_change_cursor_callback, FnMut(&View, Cursor));
+
+    /// See the method of the same name on `View`.
+    pub fn set_begin_loading_callback<T>(&mut self, cb: T) -> Result<()>
+        where T: FnMut(&View) + 'static
+    {
+        let loads = self.loads.clone();
+
+        Ultralight::attach_begin_loading(self.view_mut()?, &loads, Some(Box::new(cb)));
+
+        Ok(())
+    }
+
+    pub fn clear_begin_loading_callback(&mut self) -> Result<()> {
+        let loads = self.loads.clone();
+
+        Ultralight::attach_begin_loading(self.view_mut()?, &loads, None);
+
+        Ok(())
+    }
+
+    /// See the method of the same name on `View`.
+    pub fn set_finish_loading_callback<T>(&mut self, cb: T) -> Result<()>
+        where T: FnMut(&View) + 'static
+    {
+        let loads = self.loads.clone();
+
+        Ultralight::attach_finish_loading(self.view_mut()?, &loads, Some(Box::new(cb)));
+
+        Ok(())
+    }
+
+    pub fn clear_finish_loading_callback(&mut self) -> Result<()> {
+        let loads = self.loads.clone();
+
+        Ultralight::attach_finish_loading(self.view_mut()?, &loads, None);
+
+        Ok(())
+    }
+
+    forward_view_callback!(set_update_history_callback, clear_update_history_callback, FnMut(&View));
+    forward_view_callback!(set_dom_ready_callback, clear_dom_ready_callback, FnMut(&View));
+
+    /// See the method of the same name on `View`.
+    ///
+    /// The callback runs next to the `on_console_message` sink and the
+    /// console error policy, and stays in place when the view is replaced.
+    pub fn set_console_message_callback<T>(&mut self, cb: T) -> Result<()>
+        where T: FnMut(&View, &ConsoleMessage) + 'static
+    {
+        self.console.borrow_mut().callback = Some(Box::new(cb));
+
+        Ok(())
+    }
+
+    pub fn clear_console_message_callback(&mut self) -> Result<()> {
+        self.console.borrow_mut().callback = None;
+
+        Ok(())
+    }
+
+    /// Deliver every console message to `sink`, e.g. one of the sinks in
+    /// the `console` module. The sink stays in place when the view is
+    /// replaced.
+    ///
+    /// Replaces the sink installed earlier, including `log_to_stdout`.
+    pub fn on_console_message<T>(&mut self, sink: T) -> Result<()>
+        where T: FnMut(&ConsoleMessage) + 'static
+    {
+        self.console.borrow_mut().sink = Some(Box::new(sink));
+
+        Ok(())
+    }
+
+    /// Create a JS function object that owns `hook`; install it with
+    /// `set_js_object_property`.
+    pub fn create_function<T>(
+        &mut self,
+        hook: T
+    ) -> Result<ffi::JSObjectRef>
+        where T: FnMut(
+            ffi::JSContextRef,
+            ffi::JSObjectRef,
+            ffi::JSObjectRef,
+            usize,
+            *const ffi::JSValueRef,
+            *mut ffi::JSValueRef,
+        ) -> ffi::JSValueRef + 'static
+    {
+        Ok(
+            create_js_function(
+                self.view_ref()?,
+                hook
+            )
+        )
+    }
+
+    /// # Safety
+    ///
+    /// `object` must be a live value belonging to this view's JS context,
+    /// such as one returned by `create_function`.
+    pub unsafe fn set_js_object_property(
+        &mut self,
+        name: impl AsRef<str>,
+        object: ffi::JSObjectRef
+    ) -> Result<()> {
+        set_js_object_property(
+            self.view_ref()?,
+            name.as_ref(),
+            object
+        )
+    }
+
+    pub fn evaluate_script(
+        &mut self,
+        script: impl AsRef<str>,
+    ) -> Result<ffi::JSValueRef> {
+        evaluate_script(self.view_ref()?, script.as_ref())
+    }
+
+    /// Check `script` for syntax errors without running it.
+    pub fn check_syntax(
+        &mut self,
+        script: impl AsRef<str>,
+    ) -> Result<()> {
+        check_script_syntax(self.view_ref()?, script.as_ref())
+    }
+
+    /// Evaluate `script` and convert its result into `T`.
+    pub fn evaluate<T: FromJs>(
+        &mut self,
+        script: impl AsRef<str>,
+    ) -> Result<T> {
+        let (jsgctx, _) = helpers::getJSContextFromView(self.view_ref()?);
+        let value = self.evaluate_script(script)?;
+
+        if T::IGNORES_VALUE {
+            return T::from_js(JsValue::Undefined);
+        }
+
+        T::from_js(unsafe {
+            JsValue::from_raw(jsgctx, value)?
+        })
+    }
+
+    /// Copy of the view's pixel buffer; rows are `ulBitmapGetRowBytes`
+    /// apart, see `raw_row_bytes`.
+    pub fn get_raw_pixels(&mut self) -> Result<Vec<u8>> {
+        unsafe {
+            let bitmap_obj = ffi::ulViewGetBitmap( self.raw_view()? );
+
+            let bitmap = ffi::ulBitmapLockPixels(bitmap_obj);
+            let bitmap_size = ffi::ulBitmapGetSize(bitmap_obj);
+
+            // copy while the pixels are still locked
+            let pixels = std::slice::from_raw_parts(
+                bitmap as *const u8,
+                bitmap_size,
+            ).to_vec();
+
+            ffi::ulBitmapUnlockPixels(bitmap_obj);
+
+            Ok(pixels)
+        }
+    }
+
+    /// Distance in bytes between two rows of `get_raw_pixels`.
+    pub fn raw_row_bytes(&self) -> Result<u32> {
+        unsafe {
+            Ok(ffi::ulBitmapGetRowBytes(ffi::ulViewGetBitmap( self.raw_view()? )))
+        }
+    }
+
+    pub fn write_png_to_file(
+        &mut self,
+        file_name: impl AsRef<Path>,
+    ) -> Result<()> {
+        let file_name = file_name.as_ref();
+
+        unsafe {
+            let bitmap_obj = ffi::ulViewGetBitmap( self.raw_view()? );
+
+            let fn_c_str = path_to_cstring(file_name)?;
+
+            if ffi::ulBitmapWritePNG(bitmap_obj, fn_c_str.as_ptr()) {
+                Ok(())
+            } else {
+                Err(Error::BitmapWriteFailed(file_name.to_path_buf()))
+            }
+        }
+    }
+
+    /// Resize the view, keeping the loaded page. The page sees a `resize`
+    /// event and is laid out again on the next update.
+    ///
+    /// Fails with `Error::InvalidSize` if either dimension is 0.
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<()> {
+        if width == 0 || height == 0 {
+            return Err(Error::InvalidSize { width, height });
+        }
+
+        self.view_ref()?.resize(width, height);
+
+        Ok(())
+    }
+
+    /// Copy of what the view currently shows.
+    pub fn capture_viewport(&mut self) -> Result<Bitmap> {
+        let (width, height) = unsafe {
+            let bitmap_obj = ffi::ulViewGetBitmap( self.raw_view()? );
+
+            (ffi::ulBitmapGetWidth(bitmap_obj), ffi::ulBitmapGetHeight(bitmap_obj))
+        };
+
+        let row_bytes = self.raw_row_bytes()?;
+
+        Ok(Bitmap::from_pixels_with_row_bytes(width, height, row_bytes, &self.get_raw_pixels()?))
+    }
+
+    /// Capture the loaded page once per width in `widths`, keeping the
+    /// current height, without reloading it. Returns the images in the
+    /// order of `widths`.
+    ///
+    /// The view is left at the last width. Fails with `Error::InvalidSize`
+    /// before resizing anything if a width is 0.
+    pub fn capture_breakpoints(&mut self, widths: &[u32], full_page: bool) -> Result<Vec<Bitmap>> {
+        let height = unsafe {
+            ffi::ulBitmapGetHeight(ffi::ulViewGetBitmap( self.raw_view()? ))
+        };
+
+        if widths.contains(&0) {
+            return Err(Error::InvalidSize { width: 0, height });
+        }
+
+        let mut captures = Vec::with_capacity(widths.len());
+
+        for &width in widths {
+            self.resize(width, height)?;
+            self.evaluate_script("window.scrollTo(0, 0)")?;
+
+            // let the page handle the resize before painting
+            self.update();
+            self.render();
+
+            captures.push(if full_page {
+                self.capture_full_page()?
+            } else {
+                self.capture_viewport()?
+            });
+        }
+
+        Ok(captures)
+    }
+
+    /// Render the whole document by scrolling through it one viewport at a
+    /// time and stitching the frames together.
+    ///
+    /// Fails with `Error::InvalidSize` if the view has no area and with
+    /// `Error::EmptyDocument` if the document has no height.
+    pub fn capture_full_page(&mut self) -> Result<Bitmap> {
+        let (width, height) = unsafe {
+            let bitmap_obj = ffi::ulViewGetBitmap( self.raw_view()? );
+
+            (ffi::ulBitmapGetWidth(bitmap_obj), ffi::ulBitmapGetHeight(bitmap_obj))
+        };
+
+        // a view created with a zero size cannot be scrolled through
+        if width == 0 || height == 0 {
+            return Err(Error::InvalidSize { width, height });
+        }
+
+        self.render();
+
+        let row_bytes = self.raw_row_bytes()?;
+        let stride = row_bytes as usize;
+
+        let scroll_height = self.get_scroll_height()? as u32;
+
+        if scroll_height == 0 {
+            return Err(Error::EmptyDocument);
+        }
+
+        let full_frames = scroll_height / height;
+        let remainder = scroll_height % height;
+        let frame_count = if remainder == 0 { full_frames } else { full_frames + 1 };
+
+        let mut frames: Vec<u8> = Vec::with_capacity(scroll_height as usize * stride);
+
+        for i in 0..frame_count {
+            let pixels = self.get_raw_pixels()?;
+
+            if i < full_frames {
+                frames.extend_from_slice(&pixels[..height as usize * stride]);
+            } else {
+                // A document shorter than the viewport sits at the top of
+                // the only frame; otherwise the last scroll stopped at the
+                // bottom and the rows not seen yet are the last ones.
+                let skip_rows = if full_frames == 0 { 0 } else { (height - remainder) as usize };
+
+                frames.extend_from_slice(
+                    &pixels[skip_rows * stride..(skip_rows + remainder as usize) * stride]
+                );
+            }
+
+            self.scroll(0, -(height as i32))?;
+            self.render();
+        }
+
+        Ok(Bitmap::from_pixels_with_row_bytes(
+            width,
+            scroll_height,
+            row_bytes,
+            &frames,
+        ))
+    }
+
+    /// URL of the page in the main frame.
+    pub fn url(&self) -> Result<String> {
+        Ok(self.view_ref()?.url())
+    }
+
+    /// Title of the page in the main frame.
+    pub fn title(&self) -> Result<String> {
+        Ok(self.view_ref()?.title())
+    }
+
+    /// URLs the main frame went through since the last `load_url`,
+    /// `load_html` or awaited navigation, in order; the last one is where
+    /// redirects ended up.
+    pub fn redirect_chain(&self) -> Vec<String> {
+        self.url_chain.borrow().clone()
+    }
+
+    pub fn is_loading(&self) -> bool {
+        match self.view {
+            Some(ref view) => view.is_loading(),
+            None => false
+        }
+    }
+
+    /// Print console messages to stdout.
+    pub fn log_to_stdout(&mut self) -> Result<()> {
+        self.on_console_message(console::stdout())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    ffi,
+    Error,
+    Result,
+    Ultralight,
+    View,
+};
+
+use std::{
+    cell::Cell,
+    time::Duration,
+};
+
+/// Number of main frame loads that began and finished in the views of an
+/// `Ultralight`.
+#[derive(Default)]
+pub(crate) struct LoadCounter {
+    pub(crate) begun: Cell<u64>,
+    pub(crate) finished: Cell<u64>,
+}
+
+impl<'r> View<'r> {
+    pub fn can_go_back(&self) -> bool {
+        unsafe {
+            ffi::ulViewCanGoBack(self.raw())
+        }
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        unsafe {
+            ffi::ulViewCanGoForward(self.raw())
+        }
+    }
+
+    pub fn go_back(&self) {
+        unsafe {
+            ffi::ulViewGoBack(self.raw());
+        }
+    }
+
+    pub fn go_forward(&self) {
+        unsafe {
+            ffi::ulViewGoForward(self.raw());
+        }
+    }
+
+    /// Navigate `offset` entries through the history; negative goes back.
+    pub fn go_to_history_offset(&self, offset: i32) {
+        unsafe {
+            ffi::ulViewGoToHistoryOffset(self.raw(), offset);
+        }
+    }
+
+    pub fn reload(&self) {
+        unsafe {
+            ffi::ulViewReload(self.raw());
+        }
+    }
+
+    /// Stop all page loads.
+    pub fn stop(&self) {
+        unsafe {
+            ffi::ulViewStop(self.raw());
+        }
+    }
+}
+
+impl Ultralight {
+    pub fn can_go_back(&self) -> Result<bool> {
+        Ok(self.view_ref()?.can_go_back())
+    }
+
+    pub fn can_go_forward(&self) -> Result<bool> {
+        Ok(self.view_ref()?.can_go_forward())
+    }
+
+    /// Go back one page. Does nothing if there is no previous page.
+    pub fn back(&mut self) -> Result<()> {
+        self.view_ref()?.go_back();
+
+        Ok(())
+    }
+
+    /// Go forward one page. Does nothing if there is no next page.
+    pub fn forward(&mut self) -> Result<()> {
+        self.view_ref()?.go_forward();
+
+        Ok(())
+    }
+
+    /// Navigate `offset` entries through the history; negative goes back.
+    pub fn go(&mut self, offset: i32) -> Result<()> {
+        self.view_ref()?.go_to_history_offset(offset);
+
+        Ok(())
+    }
+
+    pub fn reload(&mut self) -> Result<()> {
+        self.view_ref()?.reload();
+
+        Ok(())
+    }
+
+    /// Stop all page loads.
+    pub fn stop(&mut self) -> Result<()> {
+        self.view_ref()?.stop();
+
+        Ok(())
+    }
+
+    /// `back`, then wait up to `timeout` for the page to load.
+    ///
+    /// Fails with `Error::NoHistoryEntry` right away if there is no
+    /// previous page.
+    pub fn back_and_wait(&mut self, timeout: Duration) -> Result<()> {
+        if !self.can_go_back()? {
+            return Err(Error::NoHistoryEntry);
+        }
+
+        self.navigate_and_wait(timeout, View::go_back)
+    }
+
+    /// `forward`, then wait up to `timeout` for the page to load.
+    ///
+    /// Fails with `Error::NoHistoryEntry` right away if there is no next
+    /// page.
+    pub fn forward_and_wait(&mut self, timeout: Duration) -> Result<()> {
+        if !self.can_go_forward()? {
+            return Err(Error::NoHistoryEntry);
+        }
+
+        self.navigate_and_wait(timeout, View::go_forward)
+    }
+
+    /// `go`, then wait up to `timeout` for the page to load. An offset
+    /// outside the history runs into the timeout, and so does going to an
+    /// entry of the same document with the same URL, since nothing changes.
+    pub fn go_and_wait(&mut self, offset: i32, timeout: Duration) -> Result<()> {
+        self.navigate_and_wait(timeout, |view| view.go_to_history_offset(offset))
+    }
+
+    /// `reload`, then wait up to `timeout` for the page to load.
+    pub fn reload_and_wait(&mut self, timeout: Duration) -> Result<()> {
+        self.navigate_and_wait(timeout, View::reload)
+    }
+
+    // The begin and finish callbacks do not say which load they belong to,
+    // so wait until both counters moved past their values from before
+    // `navigate` and nothing is loading any more. This catches loads that
+    // start and finish within one update as well as an earlier load that
+    // finishes after the new one began.
+    //
+    // History entries within the same document (`pushState`, fragments)
+    // load nothing, so neither callback fires; a URL change with nothing
+    // loading counts as done as well. `begin_load` emptied the URL chain,
+    // so any entry in it came from this navigation.
+    fn navigate_and_wait<F>(&mut self, timeout: Duration, navigate: F) -> Result<()>
+        where F: FnOnce(&View<'static>)
+    {
+        self.begin_load();
+
+        let begun = self.loads.begun.get();
+        let finished = self.loads.finished.get();
+
+        navigate(self.view_ref()?);
+
+        let result = self.poll_until(Some(timeout), |ul| {
+            let loaded = ul.loads.begun.get() > begun && ul.loads.finished.get() > finished;
+            let url_changed = !ul.url_chain.borrow().is_empty();
+
+            Ok((loaded || url_changed) && !ul.is_loading())
+        });
+
+        self.finish_load(result)
+    }
+}
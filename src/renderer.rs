@@ -0,0 +1,60 @@
+use crate::{
+    ffi,
+    config::UltralightConfig,
+    view::View,
+};
+
+/// Owning handle to a `ULRenderer`, destroyed on drop.
+///
+/// Ultralight expects a single renderer per application lifetime; every
+/// `View` borrows the renderer it was created from and cannot outlive it.
+pub struct Renderer {
+    raw: ffi::ULRenderer,
+}
+
+impl Renderer {
+    pub fn new(config: &UltralightConfig) -> Renderer {
+        let ulconfig = config.to_ulconfig();
+
+        // the renderer copies the config, so it may be destroyed right after
+        Renderer {
+            raw: unsafe {
+                ffi::ulCreateRenderer(ulconfig.raw())
+            }
+        }
+    }
+
+    /// Update timers and dispatch internal callbacks (JavaScript and network)
+    pub fn update(&self) {
+        unsafe {
+            ffi::ulUpdate(self.raw);
+        }
+    }
+
+    /// Render all active Views to their respective bitmaps.
+    pub fn render(&self) {
+        unsafe {
+            ffi::ulRender(self.raw);
+        }
+    }
+
+    pub fn create_view(&self, width: u32, height: u32, transparent: bool) -> View<'_> {
+        unsafe {
+            View::from_raw(
+                ffi::ulCreateView(self.raw, width, height, transparent)
+            )
+        }
+    }
+
+    pub(crate) fn raw(&self) -> ffi::ULRenderer {
+        self.raw
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ulDestroyRenderer(self.raw);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::ffi;
+
+use std::{
+    fmt,
+    mem::ManuallyDrop,
+    os::raw::c_char,
+    string::FromUtf16Error,
+};
+
+unsafe fn utf16_slice<'a>(data: *const u16, len: usize) -> &'a [u16] {
+    if data.is_null() || len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, len)
+    }
+}
+
+/// Owned `ULString`, released with `ulDestroyString` on drop.
+pub struct UlString {
+    raw: ffi::ULString,
+}
+
+impl UlString {
+    pub fn new(text: &str) -> UlString {
+        UlString {
+            raw: unsafe {
+                ffi::ulCreateStringUTF8(text.as_ptr() as *const c_char, text.len())
+            }
+        }
+    }
+
+    /// Takes ownership of `raw`; it will be destroyed when the string drops.
+    pub(crate) unsafe fn from_raw(raw: ffi::ULString) -> UlString {
+        UlString { raw }
+    }
+
+    /// Wraps a string owned by Ultralight (callback arguments, view title or
+    /// URL) without ever destroying it.
+    pub(crate) unsafe fn borrowed(raw: ffi::ULString) -> ManuallyDrop<UlString> {
+        ManuallyDrop::new(UlString::from_raw(raw))
+    }
+
+    pub(crate) fn raw(&self) -> ffi::ULString {
+        self.raw
+    }
+
+    pub fn as_utf16(&self) -> &[u16] {
+        unsafe {
+            utf16_slice(
+                ffi::ulStringGetData(self.raw),
+                ffi::ulStringGetLength(self.raw),
+            )
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            ffi::ulStringIsEmpty(self.raw)
+        }
+    }
+
+    /// Decode into a `String`, failing on unpaired surrogates.
+    pub fn to_string_strict(&self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(self.as_utf16())
+    }
+
+    /// Decode into a `String`, replacing unpaired surrogates with U+FFFD.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_utf16())
+    }
+}
+
+impl Drop for UlString {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ulDestroyString(self.raw);
+        }
+    }
+}
+
+impl From<&str> for UlString {
+    fn from(text: &str) -> UlString {
+        UlString::new(text)
+    }
+}
+
+impl From<&String> for UlString {
+    fn from(text: &String) -> UlString {
+        UlString::new(text)
+    }
+}
+
+impl From<String> for UlString {
+    fn from(text: String) -> UlString {
+        UlString::new(&text)
+    }
+}
+
+impl From<&UlString> for String {
+    fn from(text: &UlString) -> String {
+        text.to_string_lossy()
+    }
+}
+
+impl fmt::Display for UlString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_string_lossy())
+    }
+}
+
+impl fmt::Debug for UlString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string_lossy(), f)
+    }
+}
+
+/// Owned reference to a JavaScriptCore `JSStringRef`, released with
+/// `JSStringRelease` on drop.
+pub struct JsString {
+    raw: ffi::JSStringRef,
+}
+
+impl JsString {
+    pub fn new(text: &str) -> JsString {
+        // going through UTF-16 keeps interior NULs intact
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+
+        JsString {
+            raw: unsafe {
+                ffi::JSStringCreateWithCharacters(utf16.as_ptr(), utf16.len())
+            }
+        }
+    }
+
+    /// Takes over one reference to `raw`, e.g. the result of
+    /// `JSValueToStringCopy` or `JSValueCreateJSONString`.
+    pub(crate) unsafe fn from_raw(raw: ffi::JSStringRef) -> JsString {
+        JsString { raw }
+    }
+
+    pub(crate) fn raw(&self) -> ffi::JSStringRef {
+        self.raw
+    }
+
+    pub fn as_utf16(&self) -> &[u16] {
+        unsafe {
+            utf16_slice(
+                ffi::JSStringGetCharactersPtr(self.raw),
+                ffi::JSStringGetLength(self.raw),
+            )
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe {
+            ffi::JSStringGetLength(self.raw)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decode into a `String`, failing on unpaired surrogates.
+    pub fn to_string_strict(&self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(self.as_utf16())
+    }
+
+    /// Decode into a `String`, replacing unpaired surrogates with U+FFFD.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_utf16())
+    }
+}
+
+impl Clone for JsString {
+    fn clone(&self) -> JsString {
+        JsString {
+            raw: unsafe {
+                ffi::JSStringRetain(self.raw)
+            }
+        }
+    }
+}
+
+impl Drop for JsString {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::JSStringRelease(self.raw);
+        }
+    }
+}
+
+impl PartialEq for JsString {
+    fn eq(&self, other: &JsString) -> bool {
+        unsafe {
+            ffi::JSStringIsEqual(self.raw, other.raw)
+        }
+    }
+}
+
+impl From<&str> for JsString {
+    fn from(text: &str) -> JsString {
+        JsString::new(text)
+    }
+}
+
+impl From<&String> for JsString {
+    fn from(text: &String) -> JsString {
+        JsString::new(text)
+    }
+}
+
+impl From<String> for JsString {
+    fn from(text: String) -> JsString {
+        JsString::new(&text)
+    }
+}
+
+impl From<&JsString> for String {
+    fn from(text: &JsString) -> String {
+        text.to_string_lossy()
+    }
+}
+
+impl fmt::Display for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_string_lossy())
+    }
+}
+
+impl fmt::Debug for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string_lossy(), f)
+    }
+}
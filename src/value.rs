@@ -0,0 +1,501 @@
+use crate::{
+    ffi,
+    error::{
+        Error,
+        Result,
+    },
+    strings::JsString,
+};
+
+use std::{
+    collections::HashMap,
+    hash::BuildHasher,
+};
+
+// bounds on how much of an object graph `JsValue::from_raw` copies; cycles
+// such as `window.window` are detected separately, but DOM nodes reach most
+// of the document through acyclic paths too
+const MAX_DEPTH: usize = 32;
+const MAX_NODES: usize = 100_000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TypedArrayKind {
+    Int8,
+    Int16,
+    Int32,
+    Uint8,
+    Uint8Clamped,
+    Uint16,
+    Uint32,
+    Float32,
+    Float64,
+    ArrayBuffer,
+}
+
+impl TypedArrayKind {
+    fn to_raw(self) -> ffi::JSTypedArrayType {
+        match self {
+            TypedArrayKind::Int8 => ffi::JSTypedArrayType_kJSTypedArrayTypeInt8Array,
+            TypedArrayKind::Int16 => ffi::JSTypedArrayType_kJSTypedArrayTypeInt16Array,
+            TypedArrayKind::Int32 => ffi::JSTypedArrayType_kJSTypedArrayTypeInt32Array,
+            TypedArrayKind::Uint8 => ffi::JSTypedArrayType_kJSTypedArrayTypeUint8Array,
+            TypedArrayKind::Uint8Clamped => ffi::JSTypedArrayType_kJSTypedArrayTypeUint8ClampedArray,
+            TypedArrayKind::Uint16 => ffi::JSTypedArrayType_kJSTypedArrayTypeUint16Array,
+            TypedArrayKind::Uint32 => ffi::JSTypedArrayType_kJSTypedArrayTypeUint32Array,
+            TypedArrayKind::Float32 => ffi::JSTypedArrayType_kJSTypedArrayTypeFloat32Array,
+            TypedArrayKind::Float64 => ffi::JSTypedArrayType_kJSTypedArrayTypeFloat64Array,
+            TypedArrayKind::ArrayBuffer => ffi::JSTypedArrayType_kJSTypedArrayTypeArrayBuffer,
+        }
+    }
+
+    /// Size of one element in bytes.
+    pub fn element_size(self) -> usize {
+        match self {
+            TypedArrayKind::Int8 | TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped | TypedArrayKind::ArrayBuffer => 1,
+            TypedArrayKind::Int16 | TypedArrayKind::Uint16 => 2,
+            TypedArrayKind::Int32 | TypedArrayKind::Uint32 | TypedArrayKind::Float32 => 4,
+            TypedArrayKind::Float64 => 8,
+        }
+    }
+
+    fn from_raw(kind: ffi::JSTypedArrayType) -> Option<TypedArrayKind> {
+        match kind {
+            ffi::JSTypedArrayType_kJSTypedArrayTypeInt8Array => Some(TypedArrayKind::Int8),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeInt16Array => Some(TypedArrayKind::Int16),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeInt32Array => Some(TypedArrayKind::Int32),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeUint8Array => Some(TypedArrayKind::Uint8),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeUint8ClampedArray => Some(TypedArrayKind::Uint8Clamped),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeUint16Array => Some(TypedArrayKind::Uint16),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeUint32Array => Some(TypedArrayKind::Uint32),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeFloat32Array => Some(TypedArrayKind::Float32),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeFloat64Array => Some(TypedArrayKind::Float64),
+            ffi::JSTypedArrayType_kJSTypedArrayTypeArrayBuffer => Some(TypedArrayKind::ArrayBuffer),
+            _ => None,
+        }
+    }
+}
+
+/// A JavaScript value copied out of the page's JS context.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsValue {
+    Undefined,
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsValue>),
+    Object(HashMap<String, JsValue>),
+    /// Milliseconds since the Unix epoch, as returned by `Date.prototype.valueOf`.
+    Date(f64),
+    /// The raw bytes of a typed array or `ArrayBuffer`, in native byte order.
+    TypedArray(TypedArrayKind, Vec<u8>),
+}
+
+impl JsValue {
+    /// Copy `value` and everything reachable from it into Rust.
+    ///
+    /// Fails with `Error::Conversion` if the value refers back to itself or
+    /// is too deep or too large to copy.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be a live value belonging to `ctx`.
+    pub(crate) unsafe fn from_raw(ctx: ffi::JSContextRef, value: ffi::JSValueRef) -> Result<JsValue> {
+        Converter {
+            ctx,
+            stack: Vec::new(),
+            nodes: 0,
+        }.convert(value)
+    }
+
+    /// Create the equivalent value in `ctx`.
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must be a live JS context.
+    pub(crate) unsafe fn to_raw(&self, ctx: ffi::JSContextRef) -> ffi::JSValueRef {
+        match self {
+            JsValue::Undefined => ffi::JSValueMakeUndefined(ctx),
+            JsValue::Null => ffi::JSValueMakeNull(ctx),
+            JsValue::Bool(boolean) => ffi::JSValueMakeBoolean(ctx, *boolean),
+            JsValue::Number(number) => ffi::JSValueMakeNumber(ctx, *number),
+            JsValue::String(string) => {
+                let string = JsString::new(string);
+
+                ffi::JSValueMakeString(ctx, string.raw())
+            },
+            JsValue::Array(items) => {
+                let items: Vec<ffi::JSValueRef> = items.iter()
+                    .map(|item| item.to_raw(ctx))
+                    .collect();
+
+                ffi::JSObjectMakeArray(ctx, items.len(), items.as_ptr(), std::ptr::null_mut())
+            },
+            JsValue::Object(properties) => {
+                let object = ffi::JSObjectMake(ctx, std::ptr::null_mut(), std::ptr::null_mut());
+
+                for (name, property) in properties {
+                    let name = JsString::new(name);
+
+                    ffi::JSObjectSetProperty(
+                        ctx,
+                        object,
+                        name.raw(),
+                        property.to_raw(ctx),
+                        ffi::kJSPropertyAttributeNone,
+                        std::ptr::null_mut()
+                    );
+                }
+
+                object
+            },
+            JsValue::Date(millis) => {
+                let millis = ffi::JSValueMakeNumber(ctx, *millis);
+
+                ffi::JSObjectMakeDate(ctx, 1, &millis, std::ptr::null_mut())
+            },
+            JsValue::TypedArray(kind, bytes) => {
+                // an ArrayBuffer is created as the backing store of a Uint8Array
+                let array_kind = match kind {
+                    TypedArrayKind::ArrayBuffer => TypedArrayKind::Uint8,
+                    kind => *kind,
+                };
+
+                let array = ffi::JSObjectMakeTypedArray(
+                    ctx,
+                    array_kind.to_raw(),
+                    bytes.len() / array_kind.element_size(),
+                    std::ptr::null_mut()
+                );
+
+                let target = ffi::JSObjectGetTypedArrayBytesPtr(ctx, array, std::ptr::null_mut());
+                let target_length = ffi::JSObjectGetTypedArrayByteLength(ctx, array, std::ptr::null_mut());
+
+                if !target.is_null() {
+                    std::ptr::copy_nonoverlapping(bytes.as_ptr(), target as *mut u8, target_length.min(bytes.len()));
+                }
+
+                if *kind == TypedArrayKind::ArrayBuffer {
+                    ffi::JSObjectGetTypedArrayBuffer(ctx, array, std::ptr::null_mut())
+                } else {
+                    array
+                }
+            },
+        }
+    }
+
+    /// Name of the variant, used in conversion errors.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            JsValue::Undefined => "undefined",
+            JsValue::Null => "null",
+            JsValue::Bool(_) => "boolean",
+            JsValue::Number(_) => "number",
+            JsValue::String(_) => "string",
+            JsValue::Array(_) => "array",
+            JsValue::Object(_) => "object",
+            JsValue::Date(_) => "date",
+            JsValue::TypedArray(..) => "typed array",
+        }
+    }
+
+    pub fn is_null_or_undefined(&self) -> bool {
+        matches!(self, JsValue::Undefined | JsValue::Null)
+    }
+
+    fn mismatch<T>(self, expected: &'static str) -> Result<T> {
+        Err(Error::TypeMismatch {
+            expected,
+            found: self.type_name(),
+        })
+    }
+}
+
+/// State of one `JsValue::from_raw` call.
+struct Converter {
+    ctx: ffi::JSContextRef,
+    /// Objects currently being converted, outermost first.
+    stack: Vec<ffi::JSValueRef>,
+    /// Values converted so far.
+    nodes: usize,
+}
+
+impl Converter {
+    unsafe fn convert(&mut self, value: ffi::JSValueRef) -> Result<JsValue> {
+        let ctx = self.ctx;
+
+        if value.is_null() {
+            return Ok(JsValue::Undefined);
+        }
+
+        self.nodes += 1;
+
+        if self.nodes > MAX_NODES {
+            return Err(Error::Conversion(format!("value has more than {} members", MAX_NODES)));
+        }
+
+        match ffi::JSValueGetType(ctx, value) {
+            ffi::JSType_kJSTypeUndefined => Ok(JsValue::Undefined),
+            ffi::JSType_kJSTypeNull => Ok(JsValue::Null),
+            ffi::JSType_kJSTypeBoolean => Ok(JsValue::Bool(ffi::JSValueToBoolean(ctx, value))),
+            ffi::JSType_kJSTypeNumber => Ok(JsValue::Number(ffi::JSValueToNumber(ctx, value, std::ptr::null_mut()))),
+            ffi::JSType_kJSTypeString => {
+                let string = JsString::from_raw(ffi::JSValueToStringCopy(ctx, value, std::ptr::null_mut()));
+
+                Ok(JsValue::String(string.to_string_lossy()))
+            },
+            _ => {
+                if self.stack.iter().any(|&outer| ffi::JSValueIsStrictEqual(ctx, outer, value)) {
+                    return Err(Error::Conversion("cyclic value".to_string()));
+                }
+
+                if self.stack.len() >= MAX_DEPTH {
+                    return Err(Error::Conversion(format!("value nested deeper than {} levels", MAX_DEPTH)));
+                }
+
+                self.stack.push(value);
+
+                let result = self.convert_object(value);
+
+                self.stack.pop();
+
+                result
+            },
+        }
+    }
+
+    unsafe fn convert_object(&mut self, value: ffi::JSValueRef) -> Result<JsValue> {
+        let ctx = self.ctx;
+
+        if ffi::JSValueIsDate(ctx, value) {
+            return Ok(JsValue::Date(ffi::JSValueToNumber(ctx, value, std::ptr::null_mut())));
+        }
+
+        let object = ffi::JSValueToObject(ctx, value, std::ptr::null_mut());
+
+        if object.is_null() {
+            return Ok(JsValue::Undefined);
+        }
+
+        if ffi::JSValueIsArray(ctx, value) {
+            let length = JsString::new("length");
+            let length = ffi::JSValueToNumber(
+                ctx,
+                ffi::JSObjectGetProperty(ctx, object, length.raw(), std::ptr::null_mut()),
+                std::ptr::null_mut()
+            ) as u32;
+
+            let mut items = Vec::with_capacity(length as usize);
+
+            for index in 0..length {
+                let item = ffi::JSObjectGetPropertyAtIndex(ctx, object, index, std::ptr::null_mut());
+
+                items.push(self.convert(item)?);
+            }
+
+            return Ok(JsValue::Array(items));
+        }
+
+        let typed_array_kind = TypedArrayKind::from_raw(
+            ffi::JSValueGetTypedArrayType(ctx, value, std::ptr::null_mut())
+        );
+
+        if let Some(kind) = typed_array_kind {
+            let (bytes, byte_length) = if kind == TypedArrayKind::ArrayBuffer {
+                (
+                    ffi::JSObjectGetArrayBufferBytesPtr(ctx, object, std::ptr::null_mut()),
+                    ffi::JSObjectGetArrayBufferByteLength(ctx, object, std::ptr::null_mut()),
+                )
+            } else {
+                (
+                    ffi::JSObjectGetTypedArrayBytesPtr(ctx, object, std::ptr::null_mut()),
+                    ffi::JSObjectGetTypedArrayByteLength(ctx, object, std::ptr::null_mut()),
+                )
+            };
+
+            let bytes = if bytes.is_null() || byte_length == 0 {
+                Vec::new()
+            } else {
+                std::slice::from_raw_parts(bytes as *const u8, byte_length).to_vec()
+            };
+
+            return Ok(JsValue::TypedArray(kind, bytes));
+        }
+
+        let names = ffi::JSObjectCopyPropertyNames(ctx, object);
+        let count = ffi::JSPropertyNameArrayGetCount(names);
+
+        let mut properties = HashMap::with_capacity(count);
+        let mut result = Ok(());
+
+        for index in 0..count {
+            // the array owns its names, take our own reference
+            let name = JsString::from_raw(
+                ffi::JSStringRetain(ffi::JSPropertyNameArrayGetNameAtIndex(names, index))
+            );
+
+            let property = ffi::JSObjectGetProperty(ctx, object, name.raw(), std::ptr::null_mut());
+
+            match self.convert(property) {
+                Ok(property) => {
+                    properties.insert(name.to_string_lossy(), property);
+                },
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                },
+            }
+        }
+
+        ffi::JSPropertyNameArrayRelease(names);
+
+        result.map(|_| JsValue::Object(properties))
+    }
+}
+
+/// Conversion from a `JsValue` into a Rust type.
+pub trait FromJs: Sized {
+    /// Whether `from_js` ignores its argument, which lets callers skip
+    /// copying the value out of the page.
+    #[doc(hidden)]
+    const IGNORES_VALUE: bool = false;
+
+    fn from_js(value: JsValue) -> Result<Self>;
+}
+
+impl FromJs for JsValue {
+    fn from_js(value: JsValue) -> Result<JsValue> {
+        Ok(value)
+    }
+}
+
+impl FromJs for f64 {
+    fn from_js(value: JsValue) -> Result<f64> {
+        match value {
+            JsValue::Number(number) | JsValue::Date(number) => Ok(number),
+            value => value.mismatch("number"),
+        }
+    }
+}
+
+impl FromJs for bool {
+    fn from_js(value: JsValue) -> Result<bool> {
+        match value {
+            JsValue::Bool(boolean) => Ok(boolean),
+            value => value.mismatch("boolean"),
+        }
+    }
+}
+
+impl FromJs for String {
+    fn from_js(value: JsValue) -> Result<String> {
+        match value {
+            JsValue::String(string) => Ok(string),
+            value => value.mismatch("string"),
+        }
+    }
+}
+
+impl FromJs for () {
+    const IGNORES_VALUE: bool = true;
+
+    fn from_js(_value: JsValue) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: FromJs> FromJs for Vec<T> {
+    fn from_js(value: JsValue) -> Result<Vec<T>> {
+        match value {
+            JsValue::Array(items) => items.into_iter().map(T::from_js).collect(),
+            value => value.mismatch("array"),
+        }
+    }
+}
+
+impl<T: FromJs, S: BuildHasher + Default> FromJs for HashMap<String, T, S> {
+    fn from_js(value: JsValue) -> Result<HashMap<String, T, S>> {
+        match value {
+            JsValue::Object(properties) => properties.into_iter()
+                .map(|(name, property)| Ok((name, T::from_js(property)?)))
+                .collect(),
+            value => value.mismatch("object"),
+        }
+    }
+}
+
+impl<T: FromJs> FromJs for Option<T> {
+    fn from_js(value: JsValue) -> Result<Option<T>> {
+        if value.is_null_or_undefined() {
+            Ok(None)
+        } else {
+            T::from_js(value).map(Some)
+        }
+    }
+}
+
+/// Conversion from a Rust type into a `JsValue`.
+pub trait ToJs {
+    fn to_js(self) -> JsValue;
+}
+
+impl ToJs for JsValue {
+    fn to_js(self) -> JsValue {
+        self
+    }
+}
+
+impl ToJs for f64 {
+    fn to_js(self) -> JsValue {
+        JsValue::Number(self)
+    }
+}
+
+impl ToJs for bool {
+    fn to_js(self) -> JsValue {
+        JsValue::Bool(self)
+    }
+}
+
+impl ToJs for String {
+    fn to_js(self) -> JsValue {
+        JsValue::String(self)
+    }
+}
+
+impl ToJs for &str {
+    fn to_js(self) -> JsValue {
+        JsValue::String(self.to_string())
+    }
+}
+
+impl ToJs for () {
+    fn to_js(self) -> JsValue {
+        JsValue::Undefined
+    }
+}
+
+impl<T: ToJs> ToJs for Vec<T> {
+    fn to_js(self) -> JsValue {
+        JsValue::Array(self.into_iter().map(T::to_js).collect())
+    }
+}
+
+impl<T: ToJs, S: BuildHasher> ToJs for HashMap<String, T, S> {
+    fn to_js(self) -> JsValue {
+        JsValue::Object(
+            self.into_iter()
+                .map(|(name, property)| (name, property.to_js()))
+                .collect()
+        )
+    }
+}
+
+impl<T: ToJs> ToJs for Option<T> {
+    fn to_js(self) -> JsValue {
+        match self {
+            Some(value) => value.to_js(),
+            None => JsValue::Null,
+        }
+    }
+}
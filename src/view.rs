@@ -0,0 +1,193 @@
+use crate::{
+    ffi,
+    console::ConsoleMessage,
+    cursor::Cursor,
+    helpers_internal::{
+        view_cb_trampoline,
+        view_console_cb_trampoline,
+        view_cursor_cb_trampoline,
+        view_string_cb_trampoline,
+        ViewCallback,
+        ViewConsoleCallback,
+        ViewCursorCallback,
+        ViewStringCallback,
+    },
+    renderer::Renderer,
+    strings::UlString,
+};
+
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    os::raw::c_void,
+};
+
+macro_rules! view_callback (
+    ($set:ident, $clear:ident, $field:ident, $ffiName:ident, $trampoline:ident, $type:ty, $bound:path, $comment:expr) => (
+        #[doc = $comment]
+        ///
+        /// The closure is owned by the view and replaces any previous one.
+        pub fn $set<F>(&mut self, callback: F)
+            where F: $bound + 'static
+        {
+            let callback: Box<RefCell<$type>> = Box::new(RefCell::new(Box::new(callback)));
+
+            unsafe {
+                ffi::$ffiName(
+                    self.raw,
+                    Some($trampoline),
+                    &*callback as *const RefCell<$type> as *mut c_void
+                );
+            }
+
+            // the old closure may only go once Ultralight stopped pointing at it
+            self.$field = Some(callback);
+        }
+
+        /// Remove the callback installed by the matching setter.
+        pub fn $clear(&mut self) {
+            unsafe {
+                ffi::$ffiName(self.raw, None, std::ptr::null_mut());
+            }
+
+            self.$field = None;
+        }
+    )
+);
+
+/// Owning handle to a `ULView`, destroyed on drop.
+///
+/// The lifetime ties the view to the `Renderer` that created it. Event
+/// callbacks are owned by the view and live exactly as long as it does.
+pub struct View<'r> {
+    raw: ffi::ULView,
+    change_title: Option<Box<RefCell<ViewStringCallback>>>,
+    change_url: Option<Box<RefCell<ViewStringCallback>>>,
+    change_tooltip: Option<Box<RefCell<ViewStringCallback>>>,
+    change_cursor: Option<Box<RefCell<ViewCursorCallback>>>,
+    begin_loading: Option<Box<RefCell<ViewCallback>>>,
+    finish_loading: Option<Box<RefCell<ViewCallback>>>,
+    update_history: Option<Box<RefCell<ViewCallback>>>,
+    dom_ready: Option<Box<RefCell<ViewCallback>>>,
+    console_message: Option<Box<RefCell<ViewConsoleCallback>>>,
+    _renderer: PhantomData<&'r Renderer>,
+}
+
+impl<'r> View<'r> {
+    /// Takes ownership of `raw`; it will be destroyed when the view drops.
+    ///
+    /// The caller picks `'r` and must make sure the view is dropped before
+    /// the renderer that created it.
+    pub(crate) unsafe fn from_raw(raw: ffi::ULView) -> View<'r> {
+        View {
+            raw,
+            change_title: None,
+            change_url: None,
+            change_tooltip: None,
+            change_cursor: None,
+            begin_loading: None,
+            finish_loading: None,
+            update_history: None,
+            dom_ready: None,
+            console_message: None,
+            _renderer: PhantomData,
+        }
+    }
+
+    pub(crate) fn raw(&self) -> ffi::ULView {
+        self.raw
+    }
+
+    /// URL of the page in the main frame.
+    pub fn url(&self) -> String {
+        // the string is owned by the view
+        unsafe {
+            UlString::borrowed(ffi::ulViewGetURL(self.raw)).to_string_lossy()
+        }
+    }
+
+    /// Title of the page in the main frame.
+    pub fn title(&self) -> String {
+        unsafe {
+            UlString::borrowed(ffi::ulViewGetTitle(self.raw)).to_string_lossy()
+        }
+    }
+
+    /// Resize the view to `width` x `height` pixels; the page is laid out
+    /// again on the next update.
+    pub fn resize(&self, width: u32, height: u32) {
+        unsafe {
+            ffi::ulViewResize(self.raw, width, height);
+        }
+    }
+
+    /// Check if main frame is loading.
+    pub fn is_loading(&self) -> bool {
+        unsafe {
+            ffi::ulViewIsLoading(self.raw)
+        }
+    }
+
+    view_callback!(
+        set_change_title_callback, clear_change_title_callback, change_title,
+        ulViewSetChangeTitleCallback, view_string_cb_trampoline, ViewStringCallback, FnMut(&View, String),
+        "Set callback for when the page title changes"
+    );
+
+    view_callback!(
+        set_change_url_callback, clear_change_url_callback, change_url,
+        ulViewSetChangeURLCallback, view_string_cb_trampoline, ViewStringCallback, FnMut(&View, String),
+        "Set callback for when the page URL changes"
+    );
+
+    view_callback!(
+        set_change_tooltip_callback, clear_change_tooltip_callback, change_tooltip,
+        ulViewSetChangeTooltipCallback, view_string_cb_trampoline, ViewStringCallback, FnMut(&View, String),
+        "Set callback for when the tooltip changes (usually as result of a mouse hover)"
+    );
+
+    view_callback!(
+        set_change_cursor_callback, clear_change_cursor_callback, change_cursor,
+        ulViewSetChangeCursorCallback, view_cursor_cb_trampoline, ViewCursorCallback, FnMut(&View, Cursor),
+        "Set callback for when the mouse cursor changes"
+    );
+
+    view_callback!(
+        set_begin_loading_callback, clear_begin_loading_callback, begin_loading,
+        ulViewSetBeginLoadingCallback, view_cb_trampoline, ViewCallback, FnMut(&View),
+        "Set callback for when the page begins loading new URL into main frame"
+    );
+
+    view_callback!(
+        set_finish_loading_callback, clear_finish_loading_callback, finish_loading,
+        ulViewSetFinishLoadingCallback, view_cb_trampoline, ViewCallback, FnMut(&View),
+        "Set callback for when the page finishes loading URL into main frame"
+    );
+
+    view_callback!(
+        set_update_history_callback, clear_update_history_callback, update_history,
+        ulViewSetUpdateHistoryCallback, view_cb_trampoline, ViewCallback, FnMut(&View),
+        "Set callback for when the history (back/forward state) is modified"
+    );
+
+    view_callback!(
+        set_dom_ready_callback, clear_dom_ready_callback, dom_ready,
+        ulViewSetDOMReadyCallback, view_cb_trampoline, ViewCallback, FnMut(&View),
+        "Set callback for when all JavaScript has been parsed and the document is ready."
+    );
+
+    view_callback!(
+        set_console_message_callback, clear_console_message_callback, console_message,
+        ulViewSetAddConsoleMessageCallback, view_console_cb_trampoline, ViewConsoleCallback, FnMut(&View, &ConsoleMessage),
+        "Set callback for when a message is added to the console (useful for JavaScript / network errors and debugging)"
+    );
+}
+
+impl<'r> Drop for View<'r> {
+    fn drop(&mut self) {
+        // the callback boxes are dropped after this, once the view is gone
+        unsafe {
+            ffi::ulDestroyView(self.raw);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    ffi,
+    helpers_internal::quote_string,
+    Error,
+    Result,
+    Ultralight,
+};
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// How long the waits sleep between two renderer updates.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Flag for aborting waits from elsewhere, e.g. another thread.
+///
+/// Clones share the flag. Install one with
+/// `Ultralight::set_cancellation_token`; once cancelled, every wait fails
+/// with `Error::Cancelled` until a fresh token is installed.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Something to wait for with `Ultralight::wait_for`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// An element matches the CSS selector.
+    Selector(String),
+    /// The JS expression evaluates to a truthy value. Exceptions thrown by
+    /// the expression end the wait with `Error::JsException`.
+    Expression(String),
+    /// The page calls `window[name]()`. The function is installed when the
+    /// wait starts, so the page has to call it after that.
+    Hook(String),
+    /// The time has passed. The wait's timeout does not apply.
+    Delay(Duration),
+    /// The view has not repainted anything for the given time.
+    BitmapIdle(Duration),
+}
+
+impl Ultralight {
+    /// Run the renderer until `condition` holds; fails with
+    /// `Error::Timeout` if it does not within `timeout`.
+    pub fn wait_for(&mut self, condition: Condition, timeout: Duration) -> Result<()> {
+        self.raw_view()?;
+
+        match condition {
+            Condition::Selector(selector) => {
+                let script = format!("document.querySelector({}) !== null", quote_string(&selector));
+
+                self.poll_until(Some(timeout), |ul| ul.evaluate::<bool>(&script))
+            },
+            Condition::Expression(expression) => {
+                let script = format!("!!({}\n)", expression);
+
+                self.poll_until(Some(timeout), |ul| ul.evaluate::<bool>(&script))
+            },
+            Condition::Hook(name) => {
+                let called = Rc::new(Cell::new(false));
+
+                let flag = called.clone();
+
+                // `()` converts none of the arguments, so the page may pass
+                // anything, including DOM events
+                self.register_fn(name, move |()| {
+                    flag.set(true);
+
+                    Ok(())
+                })?;
+
+                self.poll_until(Some(timeout), |_| Ok(called.get()))
+            },
+            Condition::Delay(delay) => {
+                let started = Instant::now();
+
+                // bounded by the delay itself; a timeout could only race it
+                self.poll_until(None, |_| Ok(started.elapsed() >= delay))
+            },
+            Condition::BitmapIdle(idle) => {
+                let mut last_change = Instant::now();
+
+                self.poll_until(Some(timeout), |ul| {
+                    ul.render();
+
+                    let view = ul.raw_view()?;
+
+                    unsafe {
+                        if ffi::ulViewIsBitmapDirty(view) {
+                            // fetching the bitmap resets the dirty flag
+                            ffi::ulViewGetBitmap(view);
+
+                            last_change = Instant::now();
+                        }
+                    }
+
+                    Ok(last_change.elapsed() >= idle)
+                })
+            },
+        }
+    }
+
+    /// Make all waits give up with `Error::Cancelled` once `token` is
+    /// cancelled. `None` removes the token.
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation = token;
+    }
+
+    /// Run the renderer until `done` holds, yielding between updates.
+    ///
+    /// Fails with `Error::Timeout` once `timeout` has passed and with
+    /// `Error::Cancelled` when the cancellation token fires.
+    pub(crate) fn poll_until<F>(&mut self, timeout: Option<Duration>, mut done: F) -> Result<()>
+        where F: FnMut(&mut Ultralight) -> Result<bool>
+    {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        loop {
+            if done(self)? {
+                return Ok(());
+            }
+
+            if self.cancellation.as_ref().is_some_and(CancellationToken::is_cancelled) {
+                return Err(Error::Cancelled);
+            }
+
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(Error::Timeout);
+            }
+
+            self.update();
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Like `update_until_loaded`, but gives up after `timeout`.
+    ///
+    /// On timeout or cancellation the load is stopped and `Error::Timeout`
+    /// or `Error::Cancelled` is returned.
+    pub fn wait_until_loaded(&mut self, timeout: Duration) -> Result<()> {
+        self.wait_loaded(Some(timeout))
+    }
+
+    pub(crate) fn wait_loaded(&mut self, timeout: Option<Duration>) -> Result<()> {
+        self.raw_view()?;
+
+        let result = self.poll_until(timeout, |ul| Ok(!ul.is_loading()));
+
+        self.finish_load(result)
+    }
+
+    /// Stop the load if waiting for it timed out or was cancelled, then
+    /// apply the console error policy.
+    pub(crate) fn finish_load(&mut self, result: Result<()>) -> Result<()> {
+        if let Err(Error::Timeout) | Err(Error::Cancelled) = result {
+            self.stop()?;
+        }
+
+        result?;
+
+        self.check_console_errors()
+    }
+}
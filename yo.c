@@ -28,8 +28,11 @@ void dom_ready(void* user_data, ULView view) {
 
         printf("%zu", jslen);
 
-        char* jsstr = malloc(jslen);
-        JSStringGetUTF8CString(jssr, jsstr, jslen);
+        // jslen counts UTF-16 code units; UTF-8 needs up to 3 bytes each plus the NUL
+        size_t jsbuflen = JSStringGetMaximumUTF8CStringSize(jssr);
+        char* jsstr = malloc(jsbuflen);
+        JSStringGetUTF8CString(jssr, jsstr, jsbuflen);
+        JSStringRelease(jssr);
 
         JSGlobalContextRef jsgctx = JSContextGetGlobalContext(jsc);
 